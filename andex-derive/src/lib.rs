@@ -0,0 +1,319 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(rust_2018_idioms)]
+#![warn(missing_docs)]
+
+//! Derive macro for the *andex* crate
+//!
+//! This crate is not meant to be used directly: enable the `derive`
+//! feature of *andex* and use `andex::Andexable` instead.
+
+use proc_macro::{Delimiter, TokenStream, TokenTree};
+use std::iter::Peekable;
+
+/// Turns a fieldless enum into an andex
+///
+/// The enum itself is used as the marker of an
+/// `Andex<Enum, { Enum::SIZE }>`, where `SIZE` is the number of
+/// variants, in declaration order. The derive generates:
+/// - the `Enum::SIZE` constant;
+/// - `From` conversions between the enum and the andex type;
+/// - an `AndexLike` implementation, so that the enum can index the
+///   corresponding `AndexableArray`s directly.
+#[proc_macro_derive(Andexable)]
+pub fn derive_andexable(input: TokenStream) -> TokenStream {
+    match parse_enum(input) {
+        Ok((name, variants)) => generate(&name, &variants),
+        Err(msg) => format!("::core::compile_error!({:?});", msg),
+    }
+    .parse()
+    .expect("generated code should parse")
+}
+
+/// Implements the traits of the wrapped andex for a newtype
+///
+/// The newtype must be a tuple struct with a single field, the andex.
+/// The derive generates the same implementations that the andex has:
+/// `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`,
+/// `Debug`, `Display`, `FromStr`, `TryFrom<usize>`, conversions
+/// from/to the andex and into `usize`, and `AndexLike`, which allows
+/// the newtype to index the `AndexableArray`s of the andex.
+#[proc_macro_derive(AndexNewtype)]
+pub fn derive_andex_newtype(input: TokenStream) -> TokenStream {
+    match parse_newtype(input) {
+        Ok((name, andex)) => generate_newtype(&name, &andex),
+        Err(msg) => format!("::core::compile_error!({:?});", msg),
+    }
+    .parse()
+    .expect("generated code should parse")
+}
+
+/// Skips attributes and visibility until the item keyword, which is
+/// returned
+fn skip_to_keyword(tokens: &mut impl Iterator<Item = TokenTree>) -> Option<String> {
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) => {
+                let ident = ident.to_string();
+                if ident == "enum" || ident == "struct" || ident == "union" {
+                    return Some(ident);
+                }
+            }
+            Some(_) => {}
+            None => return None,
+        }
+    }
+}
+
+/// Skips the attributes and the visibility of a field or variant
+fn skip_attributes_and_visibility(tokens: &mut Peekable<impl Iterator<Item = TokenTree>>) {
+    loop {
+        match tokens.peek() {
+            Some(TokenTree::Punct(punct)) if punct.as_char() == '#' => {
+                tokens.next();
+                tokens.next();
+            }
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "pub" => {
+                tokens.next();
+                if let Some(TokenTree::Group(group)) = tokens.peek() {
+                    if group.delimiter() == Delimiter::Parenthesis {
+                        tokens.next();
+                    }
+                }
+            }
+            _ => return,
+        }
+    }
+}
+
+/// Returns the name of the newtype and the type it wraps
+fn parse_newtype(input: TokenStream) -> Result<(String, String), String> {
+    let mut tokens = input.into_iter();
+    if skip_to_keyword(&mut tokens).as_deref() != Some("struct") {
+        return Err("AndexNewtype can only be derived for structs".into());
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected the name of the struct".into()),
+    };
+    let fields = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Parenthesis => {
+            group.stream()
+        }
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err("AndexNewtype can't be derived for generic structs".into());
+        }
+        _ => {
+            return Err("AndexNewtype can only be derived for tuple structs".into());
+        }
+    };
+    let mut fields = fields.into_iter().peekable();
+    skip_attributes_and_visibility(&mut fields);
+    // The type of the field goes up to the first comma outside of
+    // angle brackets:
+    let mut andex = TokenStream::new();
+    let mut depth = 0_usize;
+    for token in fields.by_ref() {
+        if let TokenTree::Punct(punct) = &token {
+            match punct.as_char() {
+                '<' => depth += 1,
+                '>' => depth = depth.saturating_sub(1),
+                ',' if depth == 0 => break,
+                _ => {}
+            }
+        }
+        andex.extend([token]);
+    }
+    if andex.is_empty() || fields.next().is_some() {
+        return Err("AndexNewtype requires a single field, the andex".into());
+    }
+    Ok((name, andex.to_string()))
+}
+
+/// Returns the name and the variants of the enum
+fn parse_enum(input: TokenStream) -> Result<(String, Vec<String>), String> {
+    let mut tokens = input.into_iter();
+    if skip_to_keyword(&mut tokens).as_deref() != Some("enum") {
+        return Err("Andexable can only be derived for enums".into());
+    }
+    let name = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident.to_string(),
+        _ => return Err("expected the name of the enum".into()),
+    };
+    let body = match tokens.next() {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Brace => group.stream(),
+        Some(TokenTree::Punct(punct)) if punct.as_char() == '<' => {
+            return Err("Andexable can't be derived for generic enums".into());
+        }
+        _ => return Err("expected the body of the enum".into()),
+    };
+    let mut variants = Vec::new();
+    let mut expect_variant = true;
+    let mut body = body.into_iter().peekable();
+    while let Some(token) = body.next() {
+        match token {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => expect_variant = true,
+            TokenTree::Punct(punct) if punct.as_char() == '#' && expect_variant => {
+                // Attribute of the variant
+                body.next();
+            }
+            TokenTree::Ident(ident) if expect_variant => {
+                if let Some(TokenTree::Group(_)) = body.peek() {
+                    return Err("Andexable can only be derived for fieldless enums".into());
+                }
+                variants.push(ident.to_string());
+                expect_variant = false;
+            }
+            // Explicit discriminants are ignored
+            _ => {}
+        }
+    }
+    if variants.is_empty() {
+        return Err("Andexable can't be derived for enums without variants".into());
+    }
+    Ok((name, variants))
+}
+
+/// Generates the implementations for the enum
+fn generate(name: &str, variants: &[String]) -> String {
+    let size = variants.len();
+    let andex = format!("::andex::Andex<{}, {}>", name, size);
+    let andex_expr = format!("::andex::Andex::<{}, {}>", name, size);
+    let to_andex = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{}::{} => {}::new::<{}>(),", name, v, andex_expr, i))
+        .collect::<String>();
+    let from_andex = variants
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{} => {}::{},", i, name, v))
+        .collect::<String>();
+    format!(
+        r#"
+        impl {name} {{
+            /// The number of variants, which is the size of the arrays
+            /// indexed by this enum.
+            pub const SIZE: usize = {size};
+        }}
+
+        impl ::andex::AndexLike for {name} {{
+            type Andex = {andex};
+            #[inline]
+            fn andex(&self) -> {andex} {{
+                match self {{ {to_andex} }}
+            }}
+        }}
+
+        impl ::core::convert::From<{name}> for {andex} {{
+            fn from(value: {name}) -> Self {{
+                ::andex::AndexLike::andex(&value)
+            }}
+        }}
+
+        impl ::core::convert::From<{andex}> for {name} {{
+            fn from(andex: {andex}) -> Self {{
+                match andex.as_usize() {{
+                    {from_andex}
+                    _ => ::core::unreachable!(),
+                }}
+            }}
+        }}
+        "#
+    )
+}
+
+/// Generates the implementations for the newtype
+fn generate_newtype(name: &str, andex: &str) -> String {
+    format!(
+        r#"
+        impl ::core::clone::Clone for {name} {{
+            fn clone(&self) -> Self {{
+                *self
+            }}
+        }}
+
+        impl ::core::marker::Copy for {name} {{}}
+
+        impl ::core::cmp::PartialEq for {name} {{
+            fn eq(&self, other: &Self) -> bool {{
+                self.0 == other.0
+            }}
+        }}
+
+        impl ::core::cmp::Eq for {name} {{}}
+
+        impl ::core::cmp::PartialOrd for {name} {{
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {{
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }}
+        }}
+
+        impl ::core::cmp::Ord for {name} {{
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {{
+                ::core::cmp::Ord::cmp(&self.0, &other.0)
+            }}
+        }}
+
+        impl ::core::hash::Hash for {name} {{
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {{
+                ::core::hash::Hash::hash(&self.0, state)
+            }}
+        }}
+
+        impl ::core::fmt::Debug for {name} {{
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{
+                f.debug_tuple({name:?}).field(&self.0).finish()
+            }}
+        }}
+
+        impl ::core::fmt::Display for {name} {{
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{
+                ::core::fmt::Display::fmt(&self.0, f)
+            }}
+        }}
+
+        impl ::andex::AndexLike for {name} {{
+            type Andex = {andex};
+            #[inline]
+            fn andex(&self) -> {andex} {{
+                self.0
+            }}
+        }}
+
+        impl ::core::convert::From<{andex}> for {name} {{
+            fn from(andex: {andex}) -> Self {{
+                {name}(andex)
+            }}
+        }}
+
+        impl ::core::convert::From<{name}> for {andex} {{
+            fn from(newtype: {name}) -> Self {{
+                newtype.0
+            }}
+        }}
+
+        impl ::core::convert::From<{name}> for usize {{
+            fn from(newtype: {name}) -> Self {{
+                usize::from(newtype.0)
+            }}
+        }}
+
+        impl ::core::convert::TryFrom<usize> for {name} {{
+            type Error = ::andex::Error;
+            fn try_from(value: usize) -> ::core::result::Result<Self, Self::Error> {{
+                <{andex} as ::core::convert::TryFrom<usize>>::try_from(value).map({name})
+            }}
+        }}
+
+        impl ::core::str::FromStr for {name} {{
+            type Err = ::andex::Error;
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {{
+                <{andex} as ::core::str::FromStr>::from_str(s).map({name})
+            }}
+        }}
+        "#
+    )
+}
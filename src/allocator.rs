@@ -0,0 +1,109 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! allocator module
+//!
+//! Sequential handout of [`Andex`] values.
+
+use std::convert::TryFrom;
+use std::error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::andex::*;
+
+/// Hands out consecutive [`Andex`] values
+///
+/// Useful to assign stable typed IDs while loading data, without
+/// keeping a raw counter around.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum PlayerIdMarker {}
+/// type PlayerId = Andex<PlayerIdMarker, 2>;
+///
+/// let mut allocator = AndexAllocator::<PlayerId>::default();
+/// assert_eq!(allocator.next_id(), Ok(PlayerId::FIRST));
+/// assert_eq!(allocator.next_id(), Ok(PlayerId::LAST));
+/// assert_eq!(allocator.next_id(), Err(Exhausted));
+/// allocator.reset();
+/// assert_eq!(allocator.remaining(), 2);
+/// ```
+pub struct AndexAllocator<A> {
+    next: usize,
+    andex: PhantomData<A>,
+}
+
+impl<M, const SIZE: usize> AndexAllocator<Andex<M, SIZE>> {
+    /// Creates an allocator that starts at [`Andex::FIRST`]
+    pub fn new() -> Self {
+        AndexAllocator {
+            next: 0,
+            andex: PhantomData,
+        }
+    }
+
+    /// Returns the next andex, or [`Exhausted`] if all of them were
+    /// already handed out
+    pub fn next_id(&mut self) -> Result<Andex<M, SIZE>, Exhausted> {
+        let andex = Andex::try_from(self.next).map_err(|_| Exhausted)?;
+        self.next += 1;
+        Ok(andex)
+    }
+
+    /// Returns the number of andexes that can still be handed out
+    pub fn remaining(&self) -> usize {
+        SIZE - self.next
+    }
+
+    /// Restarts the allocator at [`Andex::FIRST`]
+    pub fn reset(&mut self) {
+        self.next = 0;
+    }
+}
+
+impl<M, const SIZE: usize> Default for AndexAllocator<Andex<M, SIZE>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A> Clone for AndexAllocator<A> {
+    fn clone(&self) -> Self {
+        AndexAllocator {
+            next: self.next,
+            andex: PhantomData,
+        }
+    }
+}
+
+impl<A> fmt::Debug for AndexAllocator<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AndexAllocator<{}>({})",
+            std::any::type_name::<A>(),
+            self.next
+        )
+    }
+}
+
+/// Error returned by [`AndexAllocator::next_id`] when all andexes
+/// were handed out
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Exhausted;
+
+impl error::Error for Exhausted {}
+
+impl fmt::Display for Exhausted {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "all andex values were already allocated")
+    }
+}
@@ -17,10 +17,12 @@ use std::convert::TryFrom;
 use std::error;
 use std::fmt;
 use std::hash::{Hash, Hasher};
+use std::iter;
 use std::marker::PhantomData;
 use std::mem::MaybeUninit;
 use std::num;
 use std::ops;
+use std::ptr;
 use std::str;
 
 /* Andex index type */
@@ -42,7 +44,42 @@ use std::str;
 /// enum MyIdxMarker {}
 /// type MyIdx = Andex<MyIdxMarker, 12>;
 /// ```
-pub struct Andex<M, const SIZE: usize>(PhantomData<M>, usize);
+///
+/// The marker is held as `PhantomData<fn() -> M>`, which makes the
+/// andex `Send`, `Sync` and covariant in `M` regardless of the marker
+/// type. Any type can be used as a marker, including ones that hold
+/// raw pointers or lifetimes.
+///
+/// Andex is `#[repr(transparent)]`: it's guaranteed to have the same
+/// layout as an `usize`, so it can be used as a field of `#[repr(C)]`
+/// structs that cross FFI boundaries. Values read that way must be
+/// known to be in bounds.
+///
+/// Andex can't be the type of a const generic parameter, not even
+/// with nightly's `adt_const_params`. That requires all fields to
+/// implement `ConstParamTy`, and the marker field above can't: `fn`
+/// pointers are not valid const parameter types. Holding the marker
+/// in a field that is, such as `[M; 0]`, would make `Copy` and the
+/// auto traits depend on the marker type. We can use a `usize`
+/// parameter instead, and turn it into an andex with [`Andex::new`],
+/// which keeps the bound check at compile time:
+///
+/// ```
+/// use andex::*;
+///
+/// enum ChannelMarker {}
+/// type ChannelId = Andex<ChannelMarker, 4>;
+///
+/// struct Channel<const ID: usize>;
+///
+/// impl<const ID: usize> Channel<ID> {
+///     const ID: ChannelId = ChannelId::new::<ID>();
+/// }
+///
+/// assert_eq!(Channel::<3>::ID, ChannelId::LAST);
+/// ```
+#[repr(transparent)]
+pub struct Andex<M, const SIZE: usize>(PhantomData<fn() -> M>, usize);
 
 /// Andex-wide methods
 ///
@@ -59,6 +96,50 @@ impl<M, const SIZE: usize> Andex<M, SIZE> {
     /// The last possible value.
     pub const LAST: Andex<M, SIZE> = Andex(PhantomData, SIZE - 1);
 
+    /// The number of valid values, which is the same as `SIZE`.
+    pub const COUNT: usize = SIZE;
+
+    /// The smallest value, an alias of [`Self::FIRST`].
+    pub const MIN: Andex<M, SIZE> = Self::FIRST;
+
+    /// The largest value, an alias of [`Self::LAST`].
+    pub const MAX: Andex<M, SIZE> = Self::LAST;
+
+    /// All possible values, in order
+    ///
+    /// Built at compile time, this allows us to go through every
+    /// index where iterators are not available, such as `const fn`s:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 4>;
+    ///
+    /// const fn squares() -> [usize; MyIdx::SIZE] {
+    ///     let mut squares = [0; MyIdx::SIZE];
+    ///     let mut i = 0;
+    ///     while i < MyIdx::SIZE {
+    ///         let value = MyIdx::ALL[i].as_usize();
+    ///         squares[i] = value * value;
+    ///         i += 1;
+    ///     }
+    ///     squares
+    /// }
+    ///
+    /// const SQUARES: [usize; MyIdx::SIZE] = squares();
+    /// assert_eq!(SQUARES, [0, 1, 4, 9]);
+    /// ```
+    pub const ALL: [Andex<M, SIZE>; SIZE] = {
+        let mut all = [Self::FIRST; SIZE];
+        let mut i = 0;
+        while i < SIZE {
+            all[i] = Andex(PhantomData, i);
+            i += 1;
+        }
+        all
+    };
+
     /// Create a new andex instance
     ///
     /// We recomment using this method in `const` contexts, passing
@@ -85,15 +166,74 @@ impl<M, const SIZE: usize> Andex<M, SIZE> {
     ///
     /// const MYVALUE : MyIdx = MyIdx::new::<15>();
     /// ```
+    ///
+    /// Neither does this, as the check is done at compile time in any
+    /// context, even when the result is only used at runtime:
+    /// ```compile_fail
+    /// use andex::*;
+    ///
+    /// struct MyIdxMarker;
+    /// type MyIdx = Andex<MyIdxMarker, 13>;
+    ///
+    /// let myvalue = MyIdx::new::<15>();
+    /// ```
+    ///
+    /// The error message can't include the values, but the compiler
+    /// notes point at the `Andex::<M, SIZE>::new::<N>` instantiation
+    /// that failed, which shows the offending value and the size.
     #[inline]
     pub const fn new<const N: usize>() -> Self {
-        // Trick for compile-time check of N:
-        const ASSERT: [(); 1] = [(); 1];
-        #[allow(clippy::no_effect)]
-        ASSERT[(N >= SIZE) as usize];
+        const {
+            assert!(
+                N < SIZE,
+                "andex value out of bounds: N must be less than SIZE"
+            )
+        };
         Andex(PhantomData, N)
     }
 
+    /// Create a new andex instance from a runtime value, or `None` if
+    /// it's out of bounds
+    ///
+    /// Unlike `try_from`, this can be used in `const` contexts.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// const LAST: Option<MyIdx> = MyIdx::new_checked(11);
+    /// assert_eq!(LAST, Some(MyIdx::LAST));
+    /// assert_eq!(MyIdx::new_checked(12), None);
+    /// ```
+    #[inline]
+    pub const fn new_checked(value: usize) -> Option<Self> {
+        if value < SIZE {
+            Some(Andex(PhantomData, value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the numeric value of the andex
+    ///
+    /// Same as `usize::from`, but usable in `const` contexts.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// const TABLE_LEN: usize = MyIdx::LAST.as_usize() + 1;
+    /// assert_eq!(TABLE_LEN, 12);
+    /// ```
+    #[inline]
+    pub const fn as_usize(self) -> usize {
+        self.1
+    }
+
     /// Returns the pair of the provided Andex.
     ///
     /// The "pair" is the element that is at the same distance from
@@ -101,34 +241,396 @@ impl<M, const SIZE: usize> Andex<M, SIZE> {
     /// instance, the pair of [`Self::FIRST`] is [`Self::LAST`].
     #[inline]
     pub const fn pair(self) -> Self {
-        Andex(PhantomData, SIZE - self.1 - 1)
+        Self::new_unchecked(SIZE - self.1 - 1)
+    }
+
+    /// Returns the pair of the andex around the provided pivot, or
+    /// `None` if it would be out of bounds
+    ///
+    /// This is the element that is at the same distance from `pivot`,
+    /// on the other side.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// let pivot = MyIdx::new::<4>();
+    /// assert_eq!(MyIdx::new::<3>().checked_pair_around(pivot), Some(MyIdx::new::<5>()));
+    /// assert_eq!(MyIdx::new::<9>().checked_pair_around(pivot), None);
+    /// ```
+    #[inline]
+    pub const fn checked_pair_around(self, pivot: Self) -> Option<Self> {
+        if self.1 <= pivot.1 {
+            self.checked_add((pivot.1 - self.1).saturating_mul(2))
+        } else {
+            self.checked_sub((self.1 - pivot.1).saturating_mul(2))
+        }
+    }
+
+    /// Returns the pair of the andex around the provided pivot
+    ///
+    /// Panics if the pair is out of bounds; see
+    /// [`Self::checked_pair_around`] for the non-panicking version.
+    #[inline]
+    pub const fn pair_around(self, pivot: Self) -> Self {
+        match self.checked_pair_around(pivot) {
+            Some(pair) => pair,
+            None => panic!("pair around pivot is out of bounds"),
+        }
+    }
+
+    /// Returns true if this is [`Self::FIRST`]
+    #[inline]
+    pub const fn is_first(self) -> bool {
+        self.1 == 0
+    }
+
+    /// Returns true if this is [`Self::LAST`]
+    #[inline]
+    pub const fn is_last(self) -> bool {
+        self.1 == SIZE - 1
     }
 
     /// Return the next Andex in sequence, or None if it's the last one.
     #[inline]
-    pub fn next(self) -> Option<Self> {
-        let i = usize::from(self);
+    pub const fn next(self) -> Option<Self> {
+        let i = self.1;
         if i < SIZE - 1 {
-            Some(Andex(PhantomData, i + 1))
+            Some(Self::new_unchecked(i + 1))
+        } else {
+            None
+        }
+    }
+
+    /// Return the previous Andex in sequence, or None if it's the
+    /// first one.
+    #[inline]
+    pub const fn prev(self) -> Option<Self> {
+        let i = self.1;
+        if i > 0 {
+            Some(Self::new_unchecked(i - 1))
         } else {
             None
         }
     }
 
+    /// Returns both adjacent andexes, [`Self::prev`] and
+    /// [`Self::next`]
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum CellMarker {}
+    /// type Cell = Andex<CellMarker, 5>;
+    ///
+    /// assert_eq!(Cell::new::<2>().neighbors(), (Some(Cell::new::<1>()), Some(Cell::new::<3>())));
+    /// assert_eq!(Cell::FIRST.neighbors(), (None, Some(Cell::new::<1>())));
+    /// ```
+    #[inline]
+    pub const fn neighbors(self) -> (Option<Self>, Option<Self>) {
+        (self.prev(), self.next())
+    }
+
+    /// Returns the andex `rhs` positions ahead, or `None` if it would
+    /// be out of bounds
+    #[inline]
+    pub const fn checked_add(self, rhs: usize) -> Option<Self> {
+        match self.1.checked_add(rhs) {
+            Some(value) if value < SIZE => Some(Self::new_unchecked(value)),
+            _ => None,
+        }
+    }
+
+    /// Returns the andex `rhs` positions behind, or `None` if it would
+    /// be out of bounds
+    #[inline]
+    pub const fn checked_sub(self, rhs: usize) -> Option<Self> {
+        match self.1.checked_sub(rhs) {
+            Some(value) => Some(Self::new_unchecked(value)),
+            None => None,
+        }
+    }
+
+    /// Returns the andex `rhs` positions ahead, stopping at
+    /// [`Self::LAST`]
+    #[inline]
+    pub const fn saturating_add(self, rhs: usize) -> Self {
+        match self.checked_add(rhs) {
+            Some(andex) => andex,
+            None => Self::LAST,
+        }
+    }
+
+    /// Returns the andex `rhs` positions behind, stopping at
+    /// [`Self::FIRST`]
+    #[inline]
+    pub const fn saturating_sub(self, rhs: usize) -> Self {
+        Self::new_unchecked(self.1.saturating_sub(rhs))
+    }
+
+    /// Returns the andex `rhs` positions ahead, wrapping around
+    /// after [`Self::LAST`]
+    #[inline]
+    pub const fn wrapping_add(self, rhs: usize) -> Self {
+        Self::new_unchecked((self.1 + rhs % SIZE) % SIZE)
+    }
+
+    /// Returns the andex `rhs` positions behind, wrapping around
+    /// before [`Self::FIRST`]
+    #[inline]
+    pub const fn wrapping_sub(self, rhs: usize) -> Self {
+        Self::new_unchecked((self.1 + SIZE - rhs % SIZE) % SIZE)
+    }
+
+    /// Returns the andex `delta` positions away, forward if it's
+    /// positive or backward if it's negative, or `None` if it would be
+    /// out of bounds
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 5>;
+    ///
+    /// let center = MyIdx::new::<2>();
+    /// let neighbors = [-1, 1].iter().filter_map(|&d| center.offset(d));
+    /// assert_eq!(neighbors.count(), 2);
+    /// assert_eq!(MyIdx::FIRST.offset(-1), None);
+    /// ```
+    #[inline]
+    pub const fn offset(self, delta: isize) -> Option<Self> {
+        if delta < 0 {
+            self.checked_sub(delta.unsigned_abs())
+        } else {
+            self.checked_add(delta.unsigned_abs())
+        }
+    }
+
+    /// Returns the number of positions between the two andexes,
+    /// regardless of their order
+    #[inline]
+    pub const fn distance(self, other: Self) -> usize {
+        self.1.abs_diff(other.1)
+    }
+
+    /// Returns the number of positions between the two andexes,
+    /// regardless of their order
+    ///
+    /// This is the same as [`Self::distance`], named after the
+    /// equivalent integer method.
+    #[inline]
+    pub const fn abs_diff(self, other: Self) -> usize {
+        self.distance(other)
+    }
+
+    /// Creates an andex from a value that is known to be valid
+    ///
+    /// Used internally where the value is derived from other valid
+    /// instances. With the `bounds-checks` feature, the value is
+    /// checked anyway.
+    #[inline]
+    pub(crate) const fn new_unchecked(value: usize) -> Self {
+        #[cfg(feature = "bounds-checks")]
+        assert!(value < SIZE, "andex value out of bounds");
+        Andex(PhantomData, value)
+    }
+
+    /// Converts the andex into one with a different marker and the
+    /// same size
+    ///
+    /// This is meant for index spaces that are known to coincide,
+    /// like the indexes of a reordered copy of the same entities. The
+    /// explicit method call makes these conversions easy to find.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum PlayerMarker {}
+    /// type Player = Andex<PlayerMarker, 4>;
+    /// enum RankedMarker {}
+    /// type Ranked = Andex<RankedMarker, 4>;
+    ///
+    /// const BEST: Ranked = Player::new::<2>().cast::<RankedMarker>();
+    /// assert_eq!(usize::from(BEST), 2);
+    /// ```
+    #[inline]
+    pub const fn cast<N>(self) -> Andex<N, SIZE> {
+        Andex(PhantomData, self.1)
+    }
+
+    /// Converts the andex into one of a larger index type
+    ///
+    /// The size `K` of the target type must not be smaller than
+    /// `SIZE`, which is checked at compile time. This allows indexes
+    /// of a sub-table to be used with a superset array.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum LocalMarker {}
+    /// type Local = Andex<LocalMarker, 4>;
+    /// enum GlobalMarker {}
+    /// type Global = Andex<GlobalMarker, 10>;
+    ///
+    /// let global: Global = Local::LAST.widen();
+    /// assert_eq!(usize::from(global), 3);
+    /// ```
+    ///
+    /// While the following doesn't compile:
+    ///
+    /// ```compile_fail
+    /// use andex::*;
+    ///
+    /// enum LocalMarker {}
+    /// type Local = Andex<LocalMarker, 4>;
+    /// enum GlobalMarker {}
+    /// type Global = Andex<GlobalMarker, 10>;
+    ///
+    /// let local: Local = Global::LAST.widen();
+    /// ```
+    #[inline]
+    pub const fn widen<N, const K: usize>(self) -> Andex<N, K> {
+        const { assert!(SIZE <= K, "target andex type is smaller") };
+        Andex::new_unchecked(self.1)
+    }
+
+    /// Converts the andex into one of a smaller index type, or
+    /// returns [`Error::OutOfBounds`] if the value doesn't fit
+    ///
+    /// This is the runtime-checked counterpart of [`Self::widen`],
+    /// useful to project global indexes into a sub-table.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum LocalMarker {}
+    /// type Local = Andex<LocalMarker, 4>;
+    /// enum GlobalMarker {}
+    /// type Global = Andex<GlobalMarker, 10>;
+    ///
+    /// let local: Local = Global::new::<3>().narrow().unwrap();
+    /// assert_eq!(local, Local::LAST);
+    /// assert!(Global::new::<4>().narrow::<LocalMarker, 4>().is_err());
+    /// ```
+    #[inline]
+    pub fn narrow<N, const K: usize>(self) -> Result<Andex<N, K>, Error> {
+        Andex::try_from(self.1)
+    }
+
+    /// Splits an andex of an array created by
+    /// [`AndexableArray::tile`] into the index of the repetition and
+    /// the index in the original array
+    ///
+    /// `K * N` must be equal to `SIZE`, which is checked at compile
+    /// time.
+    #[inline]
+    pub fn untile<R, O, const K: usize, const N: usize>(self) -> (Andex<R, K>, Andex<O, N>) {
+        self.decompose()
+    }
+
+    /// Splits an andex of a flattened `R x C` layout into the row and
+    /// column indexes, using division and remainder by `C`
+    ///
+    /// `R * C` must be equal to `SIZE`, which is checked at compile
+    /// time.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum CellMarker {}
+    /// type Cell = Andex<CellMarker, 12>;
+    /// enum RowMarker {}
+    /// type Row = Andex<RowMarker, 3>;
+    /// enum ColMarker {}
+    /// type Col = Andex<ColMarker, 4>;
+    ///
+    /// let (row, col): (Row, Col) = Cell::new::<6>().decompose();
+    /// assert_eq!((usize::from(row), usize::from(col)), (1, 2));
+    /// ```
+    #[inline]
+    pub const fn decompose<RowM, ColM, const R: usize, const C: usize>(
+        self,
+    ) -> (Andex<RowM, R>, Andex<ColM, C>) {
+        const { assert!(R * C == SIZE, "size must be the product of the parts") };
+        (
+            Andex::new_unchecked(self.1 / C),
+            Andex::new_unchecked(self.1 % C),
+        )
+    }
+
+    /// Combines row and column indexes into the andex of a flattened
+    /// `R x C` layout; the inverse of [`Self::decompose`]
+    ///
+    /// `R * C` must be equal to `SIZE`, which is checked at compile
+    /// time. That also guarantees that the result is in bounds.
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum CellMarker {}
+    /// type Cell = Andex<CellMarker, 12>;
+    /// enum RowMarker {}
+    /// type Row = Andex<RowMarker, 3>;
+    /// enum ColMarker {}
+    /// type Col = Andex<ColMarker, 4>;
+    ///
+    /// const CELL: Cell = Cell::compose(Row::new::<1>(), Col::new::<2>());
+    /// assert_eq!(CELL, Cell::new::<6>());
+    /// ```
+    ///
+    /// While the following doesn't compile, as the sizes don't match:
+    ///
+    /// ```compile_fail
+    /// use andex::*;
+    ///
+    /// enum CellMarker {}
+    /// type Cell = Andex<CellMarker, 12>;
+    /// enum RowMarker {}
+    /// type Row = Andex<RowMarker, 3>;
+    /// enum ColMarker {}
+    /// type Col = Andex<ColMarker, 5>;
+    ///
+    /// const CELL: Cell = Cell::compose(Row::FIRST, Col::FIRST);
+    /// ```
+    #[inline]
+    pub const fn compose<RowM, ColM, const R: usize, const C: usize>(
+        row: Andex<RowM, R>,
+        col: Andex<ColM, C>,
+    ) -> Self {
+        const { assert!(R * C == SIZE, "size must be the product of the parts") };
+        Self::new_unchecked(row.1 * C + col.1)
+    }
+
     /// Indexes the provided array
     ///
-    /// Used internally by the `Index` trait implementation.
+    /// Used internally by the `Index` trait implementation. With the
+    /// `bounds-checks` feature, the bound check is not skipped.
     #[inline]
     fn index_arr<'a, T>(&self, arr: &'a [T]) -> &'a T {
-        unsafe { arr.get_unchecked(usize::from(self)) }
+        #[cfg(feature = "bounds-checks")]
+        return &arr[usize::from(self)];
+        #[cfg(not(feature = "bounds-checks"))]
+        unsafe {
+            arr.get_unchecked(usize::from(self))
+        }
     }
 
     /// Mut-indexes the provided array
     ///
-    /// Used internally by the `IndexMut` trait implementation.
+    /// Used internally by the `IndexMut` trait implementation. With
+    /// the `bounds-checks` feature, the bound check is not skipped.
     #[inline]
     fn index_arr_mut<'a, T>(&self, arr: &'a mut [T]) -> &'a mut T {
-        unsafe { arr.get_unchecked_mut(usize::from(self)) }
+        #[cfg(feature = "bounds-checks")]
+        return &mut arr[usize::from(self)];
+        #[cfg(not(feature = "bounds-checks"))]
+        unsafe {
+            arr.get_unchecked_mut(usize::from(self))
+        }
     }
 
     /// Iterate all possible values of the index
@@ -150,8 +652,74 @@ impl<M, const SIZE: usize> Andex<M, SIZE> {
     ///     println!("{}", i);
     /// }
     /// ```
-    pub fn iter() -> AndexIterator<M, SIZE> {
-        AndexIterator::<M, SIZE>::default()
+    pub const fn iter() -> AndexIterator<M, SIZE> {
+        AndexIterator::new(0, SIZE)
+    }
+
+    /// Iterate all possible values of the index in reverse order
+    ///
+    /// Yields from [`Self::LAST`] down to [`Self::FIRST`]; this is the
+    /// same as `iter().rev()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 3>;
+    ///
+    /// assert!(MyIdx::iter_rev().map(usize::from).eq([2, 1, 0]));
+    /// ```
+    pub fn iter_rev() -> iter::Rev<AndexIterator<M, SIZE>> {
+        Self::iter().rev()
+    }
+
+    /// Iterate the values from `start` up to [`Self::LAST`]
+    ///
+    /// Useful to resume an iteration from a saved position.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// let values = MyIdx::iter_from(MyIdx::new::<9>()).map(usize::from);
+    /// assert!(values.eq([9, 10, 11]));
+    /// ```
+    pub const fn iter_from(start: Self) -> AndexIterator<M, SIZE> {
+        AndexIterator::new(start.1, SIZE)
+    }
+
+    /// Iterate the values from `start` up to, but not including, `end`
+    ///
+    /// Yields nothing if `end` is not after `start`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// let start = MyIdx::new::<3>();
+    /// let end = MyIdx::new::<6>();
+    /// let values = MyIdx::iter_range(start, end).map(usize::from);
+    /// assert!(values.eq([3, 4, 5]));
+    /// ```
+    pub const fn iter_range(start: Self, end: Self) -> AndexIterator<M, SIZE> {
+        AndexIterator::new_clamped(start.1, end.1)
+    }
+
+    /// Iterate the values from `start` up to and including `end`
+    ///
+    /// Yields nothing if `end` is before `start`.
+    pub const fn iter_range_inclusive(start: Self, end: Self) -> AndexIterator<M, SIZE> {
+        AndexIterator::new_clamped(start.1, end.1 + 1)
     }
 }
 
@@ -178,234 +746,1678 @@ impl<M, const SIZE: usize> Default for Andex<M, SIZE> {
     fn default() -> Self {
         Andex(PhantomData, 0)
     }
-}
+}
+
+impl<M, const SIZE: usize> PartialEq for Andex<M, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<M, const SIZE: usize> Eq for Andex<M, SIZE> {}
+
+impl<M, const SIZE: usize> PartialOrd for Andex<M, SIZE> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M, const SIZE: usize> Ord for Andex<M, SIZE> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+/// Compares the value of the andex with a plain number
+///
+/// Only the `andex == number` direction is implemented: implementing
+/// `PartialEq<Andex>` for `usize` would make comparisons between
+/// plain numbers ambiguous for type inference, as in
+/// `vec_of_usize == vec![]`.
+impl<M, const SIZE: usize> PartialEq<usize> for Andex<M, SIZE> {
+    fn eq(&self, other: &usize) -> bool {
+        self.1 == *other
+    }
+}
+
+/// Compares the value of the andex with a plain number
+impl<M, const SIZE: usize> PartialOrd<usize> for Andex<M, SIZE> {
+    fn partial_cmp(&self, other: &usize) -> Option<cmp::Ordering> {
+        Some(self.1.cmp(other))
+    }
+}
+
+impl<M, const SIZE: usize> From<Andex<M, SIZE>> for usize {
+    fn from(andex: Andex<M, SIZE>) -> Self {
+        andex.1
+    }
+}
+
+impl<M, const SIZE: usize> From<&Andex<M, SIZE>> for usize {
+    fn from(andex: &Andex<M, SIZE>) -> Self {
+        andex.1
+    }
+}
+
+/// Implements `From<Andex>` for the integer types that can hold all
+/// values of the andex
+///
+/// Checked at compile time against `SIZE`.
+macro_rules! andex_into_impl {
+    ($($t:ty),+) => {
+        $(
+            impl<M, const SIZE: usize> From<Andex<M, SIZE>> for $t {
+                fn from(andex: Andex<M, SIZE>) -> Self {
+                    const {
+                        assert!(
+                            SIZE as u128 - 1 <= <$t>::MAX as u128,
+                            "andex values don't fit in the target integer type"
+                        )
+                    };
+                    andex.1 as $t
+                }
+            }
+        )+
+    };
+}
+
+andex_into_impl!(u32, u64, i64);
+
+impl<M, const SIZE: usize> convert::TryFrom<usize> for Andex<M, SIZE> {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        if value < SIZE {
+            Ok(Andex(PhantomData, value))
+        } else {
+            Err(Error::OutOfBounds { value, size: SIZE })
+        }
+    }
+}
+
+/// Implements `TryFrom` for the other integer types
+///
+/// The signed types are also covered so that unsuffixed literals, as
+/// in `MyIdx::try_from(2)`, keep working through the `i32` fallback.
+/// Values that don't fit in a `usize`, including negative ones, are
+/// reported as they were provided in [`Error::NotRepresentable`].
+macro_rules! andex_try_from_impl {
+    ($($t:ty),+) => {
+        $(
+            impl<M, const SIZE: usize> convert::TryFrom<$t> for Andex<M, SIZE> {
+                type Error = Error;
+                fn try_from(value: $t) -> Result<Self, Self::Error> {
+                    match usize::try_from(value) {
+                        Ok(value) => Self::try_from(value),
+                        Err(_) => Err(Error::NotRepresentable {
+                            value: i128::from(value),
+                            size: SIZE,
+                        }),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+andex_try_from_impl!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl<M, const SIZE: usize> convert::TryFrom<isize> for Andex<M, SIZE> {
+    type Error = Error;
+    fn try_from(value: isize) -> Result<Self, Self::Error> {
+        Self::try_from(value as i64)
+    }
+}
+
+/// Shows the marker type name, the value and the size, as in
+/// `my_crate::MyIdxMarker(3/12)`
+impl<M, const SIZE: usize> fmt::Debug for Andex<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({}/{})",
+            std::any::type_name::<M>(),
+            usize::from(self),
+            SIZE
+        )
+    }
+}
+
+/// Shows the value; the alternate format `{:#}` also shows the size,
+/// as in `3 of 12`
+impl<M, const SIZE: usize> fmt::Display for Andex<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{} of {}", usize::from(self), SIZE)
+        } else {
+            write!(f, "{}", usize::from(self))
+        }
+    }
+}
+
+/// Parses an `usize`, accepting the `0x`, `0o` and `0b` prefixes for
+/// hexadecimal, octal and binary values
+pub(crate) fn parse_usize(s: &str) -> Result<usize, num::ParseIntError> {
+    let prefixes = [
+        ("0x", 16),
+        ("0X", 16),
+        ("0o", 8),
+        ("0O", 8),
+        ("0b", 2),
+        ("0B", 2),
+    ];
+    for (prefix, radix) in prefixes {
+        if let Some(digits) = s.strip_prefix(prefix) {
+            return usize::from_str_radix(digits, radix);
+        }
+    }
+    s.parse()
+}
+
+/// Parses a decimal value, or a hexadecimal, octal or binary one with
+/// the `0x`, `0o` or `0b` prefix
+///
+/// Values that are not numbers fail with [`Error::ParseIntError`],
+/// while numbers that are out of bounds fail with
+/// [`Error::OutOfBounds`].
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// assert_eq!("0xb".parse::<MyIdx>().unwrap(), MyIdx::LAST);
+/// assert_eq!("0b11".parse::<MyIdx>().unwrap(), MyIdx::new::<3>());
+/// assert!(matches!("x".parse::<MyIdx>(), Err(Error::ParseIntError(_))));
+/// assert_eq!(
+///     "12".parse::<MyIdx>().unwrap_err().to_string(),
+///     "value 12 is out-of-bounds for index with size 12, valid values are 0 to 11"
+/// );
+/// ```
+impl<M, const SIZE: usize> str::FromStr for Andex<M, SIZE> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(parse_usize(s)?)
+    }
+}
+
+/* Out-of-bounds policy */
+
+/// What to do when an operation would create an out-of-bounds andex
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OutOfBoundsPolicy {
+    /// Panic, like slice indexing does
+    Panic,
+    /// Use the nearest valid value, [`Andex::FIRST`] or [`Andex::LAST`]
+    Clamp,
+    /// Wrap around modulo `SIZE`
+    Wrap,
+}
+
+/// Optional marker trait that enables the ergonomic andex operators
+///
+/// Markers that implement this trait get [`Andex::from_usize`] and
+/// the `+ usize`, `- usize`, `+= usize` and `-= usize` operators on
+/// the corresponding andex types. These apply
+/// [`AndexPolicy::OUT_OF_BOUNDS`] when the result is out of bounds,
+/// which panics by default, mirroring slice indexing; each marker can
+/// choose the policy that suits its domain. Code that wants to handle
+/// the out-of-bounds case itself can use [`Andex::checked_add`] and
+/// [`Andex::checked_sub`] instead.
+///
+/// The default policy is enabled with an empty implementation:
+///
+/// ```
+/// use andex::*;
+///
+/// enum CellMarker {}
+/// type Cell = Andex<CellMarker, 8>;
+/// impl AndexPolicy for CellMarker {}
+///
+/// let mut cell = Cell::new::<3>();
+/// cell += 2;
+/// assert_eq!(cell - 1, Cell::new::<4>());
+/// assert_eq!(cell.checked_add(3), None);
+/// ```
+///
+/// Choosing another policy:
+///
+/// ```
+/// use andex::*;
+///
+/// enum SlotMarker {}
+/// type Slot = Andex<SlotMarker, 4>;
+///
+/// impl AndexPolicy for SlotMarker {
+///     const OUT_OF_BOUNDS: OutOfBoundsPolicy = OutOfBoundsPolicy::Wrap;
+/// }
+///
+/// assert_eq!(Slot::LAST + 1, Slot::FIRST);
+/// assert_eq!(Slot::FIRST - 1, Slot::LAST);
+/// assert_eq!(Slot::from_usize(6), Slot::new::<2>());
+/// ```
+pub trait AndexPolicy {
+    /// The policy applied when a result is out of bounds
+    const OUT_OF_BOUNDS: OutOfBoundsPolicy = OutOfBoundsPolicy::Panic;
+}
+
+impl<M: AndexPolicy, const SIZE: usize> Andex<M, SIZE> {
+    /// Creates an andex from the value, applying the marker's
+    /// [`AndexPolicy::OUT_OF_BOUNDS`] if it's out of bounds
+    pub fn from_usize(value: usize) -> Self {
+        if value < SIZE {
+            return Self::new_unchecked(value);
+        }
+        match M::OUT_OF_BOUNDS {
+            OutOfBoundsPolicy::Panic => panic!(
+                "value {} is out-of-bounds for index with size {}",
+                value, SIZE
+            ),
+            OutOfBoundsPolicy::Clamp => Self::LAST,
+            OutOfBoundsPolicy::Wrap => Self::new_unchecked(value % SIZE),
+        }
+    }
+}
+
+/// Moves the andex forward, applying the marker's
+/// [`AndexPolicy::OUT_OF_BOUNDS`] if the result is out of bounds
+impl<M: AndexPolicy, const SIZE: usize> ops::Add<usize> for Andex<M, SIZE> {
+    type Output = Self;
+    fn add(self, rhs: usize) -> Self {
+        match M::OUT_OF_BOUNDS {
+            OutOfBoundsPolicy::Panic => self
+                .checked_add(rhs)
+                .unwrap_or_else(|| panic!("andex {} plus {} is out-of-bounds", self.1, rhs)),
+            OutOfBoundsPolicy::Clamp => self.saturating_add(rhs),
+            OutOfBoundsPolicy::Wrap => self.wrapping_add(rhs),
+        }
+    }
+}
+
+/// Moves the andex backward, applying the marker's
+/// [`AndexPolicy::OUT_OF_BOUNDS`] if the result is out of bounds
+impl<M: AndexPolicy, const SIZE: usize> ops::Sub<usize> for Andex<M, SIZE> {
+    type Output = Self;
+    fn sub(self, rhs: usize) -> Self {
+        match M::OUT_OF_BOUNDS {
+            OutOfBoundsPolicy::Panic => self
+                .checked_sub(rhs)
+                .unwrap_or_else(|| panic!("andex {} minus {} is out-of-bounds", self.1, rhs)),
+            OutOfBoundsPolicy::Clamp => self.saturating_sub(rhs),
+            OutOfBoundsPolicy::Wrap => self.wrapping_sub(rhs),
+        }
+    }
+}
+
+/// Moves the andex forward in place, like `+`
+impl<M: AndexPolicy, const SIZE: usize> ops::AddAssign<usize> for Andex<M, SIZE> {
+    fn add_assign(&mut self, rhs: usize) {
+        *self = *self + rhs;
+    }
+}
+
+/// Moves the andex backward in place, like `-`
+impl<M: AndexPolicy, const SIZE: usize> ops::SubAssign<usize> for Andex<M, SIZE> {
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = *self - rhs;
+    }
+}
+
+/* AndexLike trait */
+
+/// Types that can be used as an andex
+///
+/// This trait is implemented by [`Andex`] itself and by references to
+/// it. Users can implement it for their own newtypes that wrap an
+/// `Andex`, which allows them to add domain-specific methods to the
+/// index while still using it to index [`AndexableArray`]s.
+///
+/// An [`AndexableArray`] can be indexed by any `AndexLike` type that
+/// corresponds to the same `Andex` as its own index type.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum PlayerIdxMarker {}
+/// type PlayerIdx = Andex<PlayerIdxMarker, 4>;
+///
+/// #[derive(Clone, Copy)]
+/// struct PlayerId(PlayerIdx);
+///
+/// impl PlayerId {
+///     fn is_host(&self) -> bool {
+///         self.0 == PlayerIdx::FIRST
+///     }
+/// }
+///
+/// impl AndexLike for PlayerId {
+///     type Andex = PlayerIdx;
+///     fn andex(&self) -> PlayerIdx {
+///         self.0
+///     }
+/// }
+///
+/// type Scores = AndexableArray<PlayerId, u32, { PlayerIdx::SIZE }>;
+///
+/// let mut scores = Scores::default();
+/// let host = PlayerId(PlayerIdx::FIRST);
+/// assert!(host.is_host());
+/// scores[host] = 5;
+/// assert_eq!(scores[&host], 5);
+/// ```
+pub trait AndexLike {
+    /// The wrapped andex type
+    type Andex;
+    /// Returns the wrapped andex
+    fn andex(&self) -> Self::Andex;
+}
+
+impl<M, const SIZE: usize> AndexLike for Andex<M, SIZE> {
+    type Andex = Self;
+    #[inline]
+    fn andex(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: AndexLike> AndexLike for &T {
+    type Andex = T::Andex;
+    #[inline]
+    fn andex(&self) -> Self::Andex {
+        (*self).andex()
+    }
+}
+
+/* Indexable trait */
+
+/// Generic interface to all andex types
+///
+/// This trait is implemented by all [`Andex`] types, and allows us to
+/// write code that is generic over index families.
+///
+/// Example that prints any andexable array, one item per line:
+///
+/// ```
+/// use std::fmt::Display;
+/// use andex::*;
+///
+/// fn print_table<I, T, const SIZE: usize>(table: &AndexableArray<I, T, SIZE>)
+/// where
+///     I: Indexable,
+///     T: Display,
+/// {
+///     for (index, item) in I::iter().zip(table.iter()) {
+///         println!("{}/{}: {}", index, I::SIZE, item);
+///     }
+/// }
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+/// type MyU32 = andex_array!(MyIdx, u32);
+///
+/// print_table(&MyU32::from([1, 2, 3]));
+/// ```
+pub trait Indexable:
+    Copy
+    + Ord
+    + Hash
+    + fmt::Debug
+    + fmt::Display
+    + Into<usize>
+    + TryFrom<usize, Error = Error>
+    + AndexLike<Andex = Self>
+{
+    /// The size of the array indexed by this type
+    const SIZE: usize;
+
+    /// The iterator over all values of this type
+    type Iter: Iterator<Item = Self>;
+
+    /// Returns the numeric value of the index
+    fn as_usize(self) -> usize {
+        self.into()
+    }
+
+    /// Iterates over all values of this type, in order
+    fn iter() -> Self::Iter;
+}
+
+impl<M, const SIZE: usize> Indexable for Andex<M, SIZE> {
+    const SIZE: usize = SIZE;
+    type Iter = AndexIterator<M, SIZE>;
+    fn iter() -> Self::Iter {
+        Andex::iter()
+    }
+}
+
+/* Plain arrays */
+
+/// Extension trait for indexing plain arrays with an [`Andex`]
+///
+/// This is implemented for `[T; SIZE]`, and allows us to use andex
+/// types with existing arrays while they are not converted to
+/// [`AndexableArray`]. Just like in `AndexableArray`, the bound check
+/// is skipped.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+///
+/// let mut array = [1, 2, 3];
+/// *array.at_mut(MyIdx::LAST) = 5;
+/// assert_eq!(*array.at(MyIdx::LAST), 5);
+/// ```
+pub trait AndexIndexExt<M, const SIZE: usize> {
+    /// The type of the items in the array
+    type Item;
+    /// Returns a reference to the item at the provided index
+    fn at(&self, index: Andex<M, SIZE>) -> &Self::Item;
+    /// Returns a mutable reference to the item at the provided index
+    fn at_mut(&mut self, index: Andex<M, SIZE>) -> &mut Self::Item;
+}
+
+impl<M, T, const SIZE: usize> AndexIndexExt<M, SIZE> for [T; SIZE] {
+    type Item = T;
+    #[inline]
+    fn at(&self, index: Andex<M, SIZE>) -> &T {
+        index.index_arr(self)
+    }
+    #[inline]
+    fn at_mut(&mut self, index: Andex<M, SIZE>) -> &mut T {
+        index.index_arr_mut(self)
+    }
+}
+
+/* Slices */
+
+/// Slice adapter indexable by the provided Andex type
+///
+/// Created by [`AndexChecked::try_view`], which checks once that the
+/// slice has at least `SIZE` items; after that, indexing skips the
+/// bound check just like [`AndexableArray`]. This allows us to use
+/// andex types with data that lives in `Vec`s, without copying it
+/// into arrays.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+///
+/// let data = vec![1, 2, 3, 4];
+/// let view = AndexChecked::<_, MyIdx>::try_view(&data).unwrap();
+/// assert_eq!(view[MyIdx::LAST], 3);
+/// ```
+pub struct AndexChecked<'a, T, A> {
+    slice: &'a [T],
+    andex: PhantomData<A>,
+}
+
+impl<'a, M, T, const SIZE: usize> AndexChecked<'a, T, Andex<M, SIZE>> {
+    /// Creates the adapter, or returns `None` if the slice has less
+    /// than `SIZE` items
+    pub fn try_view(slice: &'a [T]) -> Option<Self> {
+        if slice.len() >= SIZE {
+            Some(AndexChecked {
+                slice,
+                andex: PhantomData,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the part of the slice that is indexable
+    pub fn as_slice(&self) -> &'a [T] {
+        &self.slice[..SIZE]
+    }
+}
+
+impl<T, A> Clone for AndexChecked<'_, T, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, A> Copy for AndexChecked<'_, T, A> {}
+
+impl<T: fmt::Debug, A> fmt::Debug for AndexChecked<'_, T, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AndexChecked<{}>({:?})",
+            std::any::type_name::<T>(),
+            self.slice
+        )
+    }
+}
+
+impl<I, M, T, const SIZE: usize> ops::Index<I> for AndexChecked<'_, T, Andex<M, SIZE>>
+where
+    I: AndexLike<Andex = Andex<M, SIZE>>,
+{
+    type Output = T;
+    fn index(&self, index: I) -> &Self::Output {
+        index.andex().index_arr(self.slice)
+    }
+}
+
+/* Iterator */
+
+/// Iterator for Andex instances
+///
+/// This is the type returned by Andex::<_,_>::iter() and the other
+/// iterating constructors.
+/// There's no reason to use it directly.
+///
+/// Iterating example:
+///
+/// ```
+/// use andex::*;
+///
+/// pub struct PlayerIdMarker;
+/// type PlayerId = Andex<PlayerIdMarker, 12>;
+///
+/// for i in PlayerId::iter() {
+///     println!("{}", i);
+/// }
+/// ```
+pub struct AndexIterator<M, const SIZE: usize> {
+    start: usize,
+    end: usize,
+    andex: PhantomData<fn() -> M>,
+}
+
+impl<M, const SIZE: usize> AndexIterator<M, SIZE> {
+    /// Creates an iterator over the `start..end` raw values
+    #[inline]
+    const fn new(start: usize, end: usize) -> Self {
+        AndexIterator {
+            start,
+            end,
+            andex: PhantomData,
+        }
+    }
+
+    /// Creates an iterator over the `start..end` raw values, which is
+    /// empty if `end` is before `start`
+    #[inline]
+    const fn new_clamped(start: usize, end: usize) -> Self {
+        if end < start {
+            AndexIterator::new(start, start)
+        } else {
+            AndexIterator::new(start, end)
+        }
+    }
+}
+
+impl<M, const SIZE: usize> Clone for AndexIterator<M, SIZE> {
+    fn clone(&self) -> Self {
+        AndexIterator::new(self.start, self.end)
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Debug for AndexIterator<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AndexIterator({}..{})", self.start, self.end)
+    }
+}
+
+impl<M, const SIZE: usize> Default for AndexIterator<M, SIZE> {
+    fn default() -> Self {
+        AndexIterator::new(0, SIZE)
+    }
+}
+
+impl<M, const SIZE: usize> Iterator for AndexIterator<M, SIZE> {
+    type Item = Andex<M, SIZE>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let i = Andex::new_unchecked(self.start);
+            self.start += 1;
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end.saturating_sub(self.start);
+        (len, Some(len))
+    }
+
+    /// Skips `n` values in constant time
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.start = self.start.saturating_add(n).min(self.end);
+        self.next()
+    }
+}
+
+impl<M, const SIZE: usize> DoubleEndedIterator for AndexIterator<M, SIZE> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(Andex::new_unchecked(self.end))
+        } else {
+            None
+        }
+    }
+
+    /// Skips `n` values from the back in constant time
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        self.end = self.end.saturating_sub(n).max(self.start);
+        self.next_back()
+    }
+}
+
+impl<M, const SIZE: usize> ExactSizeIterator for AndexIterator<M, SIZE> {}
+
+impl<M, const SIZE: usize> iter::FusedIterator for AndexIterator<M, SIZE> {}
+
+/* Ranges */
+
+/// Range of Andex instances
+///
+/// Works like `std::ops::Range`, but it's `Copy` and always holds a
+/// valid range: `start <= end <= SIZE`. It can be created from a
+/// `a..b` or `a..=b` range of andex values, or from any other
+/// `RangeBounds` with [`AndexRange::from_bounds`].
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// let range = AndexRange::from(MyIdx::new::<3>()..=MyIdx::new::<5>());
+/// assert_eq!(range.len(), 3);
+/// assert!(range.contains(MyIdx::new::<4>()));
+/// for i in range {
+///     println!("{}", i);
+/// }
+/// ```
+pub struct AndexRange<M, const SIZE: usize> {
+    start: usize,
+    end: usize,
+    andex: PhantomData<fn() -> M>,
+}
+
+impl<M, const SIZE: usize> AndexRange<M, SIZE> {
+    /// The range with all values of the andex
+    pub const FULL: Self = AndexRange::new_unchecked(0, SIZE);
+
+    #[inline]
+    const fn new_unchecked(start: usize, end: usize) -> Self {
+        AndexRange {
+            start,
+            end,
+            andex: PhantomData,
+        }
+    }
+
+    /// Creates the range from any `RangeBounds` of andex values
+    ///
+    /// Ranges that end before they start are empty.
+    pub fn from_bounds<R: ops::RangeBounds<Andex<M, SIZE>>>(range: R) -> Self {
+        let start = match range.start_bound() {
+            ops::Bound::Included(i) => i.1,
+            ops::Bound::Excluded(i) => i.1 + 1,
+            ops::Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            ops::Bound::Included(i) => i.1 + 1,
+            ops::Bound::Excluded(i) => i.1,
+            ops::Bound::Unbounded => SIZE,
+        };
+        AndexRange::new_unchecked(start.min(end), end)
+    }
+
+    /// Returns the first value in the range, or `None` if it's empty
+    pub fn first(&self) -> Option<Andex<M, SIZE>> {
+        (!self.is_empty()).then(|| Andex::new_unchecked(self.start))
+    }
+
+    /// Returns the last value in the range, or `None` if it's empty
+    pub fn last(&self) -> Option<Andex<M, SIZE>> {
+        (!self.is_empty()).then(|| Andex::new_unchecked(self.end - 1))
+    }
+
+    /// Returns the number of values in the range
+    pub const fn len(&self) -> usize {
+        self.end - self.start
+    }
+
+    /// Returns true if the range has no values
+    pub const fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns true if the range contains the provided andex
+    pub const fn contains(&self, andex: Andex<M, SIZE>) -> bool {
+        self.start <= andex.1 && andex.1 < self.end
+    }
+
+    /// Iterates the values in the range
+    pub const fn iter(&self) -> AndexIterator<M, SIZE> {
+        AndexIterator::new(self.start, self.end)
+    }
+}
+
+impl<M, const SIZE: usize> Clone for AndexRange<M, SIZE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const SIZE: usize> Copy for AndexRange<M, SIZE> {}
+
+impl<M, const SIZE: usize> PartialEq for AndexRange<M, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        // All empty ranges are equal
+        (self.is_empty() && other.is_empty())
+            || (self.start == other.start && self.end == other.end)
+    }
+}
+
+impl<M, const SIZE: usize> Eq for AndexRange<M, SIZE> {}
+
+impl<M, const SIZE: usize> Hash for AndexRange<M, SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        if !self.is_empty() {
+            self.start.hash(state);
+            self.end.hash(state);
+        }
+    }
+}
+
+/// The empty range
+impl<M, const SIZE: usize> Default for AndexRange<M, SIZE> {
+    fn default() -> Self {
+        AndexRange::new_unchecked(0, 0)
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Debug for AndexRange<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "AndexRange({}..{})", self.start, self.end)
+    }
+}
+
+impl<M, const SIZE: usize> From<ops::Range<Andex<M, SIZE>>> for AndexRange<M, SIZE> {
+    fn from(range: ops::Range<Andex<M, SIZE>>) -> Self {
+        AndexRange::from_bounds(range)
+    }
+}
+
+impl<M, const SIZE: usize> From<ops::RangeInclusive<Andex<M, SIZE>>> for AndexRange<M, SIZE> {
+    fn from(range: ops::RangeInclusive<Andex<M, SIZE>>) -> Self {
+        AndexRange::from_bounds(range)
+    }
+}
+
+impl<M, const SIZE: usize> IntoIterator for AndexRange<M, SIZE> {
+    type Item = Andex<M, SIZE>;
+    type IntoIter = AndexIterator<M, SIZE>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<M, const SIZE: usize> IntoIterator for &AndexRange<M, SIZE> {
+    type Item = Andex<M, SIZE>;
+    type IntoIter = AndexIterator<M, SIZE>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/* Array wrapper */
+
+/// Array wrapper indexable by the provided Andex type.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// // Create the array wrapper:
+/// type MyU32 = AndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+///
+/// // We can create other arrays with the same Andex type:
+/// type MyF64 = AndexableArray<MyIdx, f64, { MyIdx::SIZE }>;
+///
+/// // Create a default array:
+/// let myu32 = MyU32::default();
+/// // Print the first element:
+/// const first : MyIdx = MyIdx::new::<0>();
+/// println!("{:?}", myu32[first]);
+/// // Iterate and print all elements:
+/// for i in MyIdx::iter() {
+///     println!("{:?}", myu32[i]);
+/// }
+/// // Print the whole array
+/// println!("{:?}", myu32);
+/// ```
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct AndexableArray<A, Item, const SIZE: usize>(PhantomData<A>, [Item; SIZE]);
+
+impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for AndexableArray<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "AndexableArray<{}>({:?})",
+            std::any::type_name::<Item>(),
+            self.1
+        )
+    }
+}
+
+/// Text representation of the array
+///
+/// The items are written in index order, separated by `", "`. This
+/// format can be parsed back with `FromStr`.
+impl<A, Item: fmt::Display, const SIZE: usize> fmt::Display for AndexableArray<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, item) in self.1.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parses the text representation of the array
+///
+/// The input must have exactly `SIZE` comma-separated items;
+/// whitespace around each item is ignored.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+/// type MyU32 = andex_array!(MyIdx, u32);
+///
+/// let myu32 = "1, 2,3".parse::<MyU32>()?;
+/// assert_eq!(myu32.to_string(), "1, 2, 3");
+/// assert!("1, 2".parse::<MyU32>().is_err());
+/// # Ok::<(), ParseArrayError<std::num::ParseIntError>>(())
+/// ```
+impl<A, Item: str::FromStr, const SIZE: usize> str::FromStr for AndexableArray<A, Item, SIZE> {
+    type Err = ParseArrayError<Item::Err>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = if s.trim().is_empty() {
+            vec![]
+        } else {
+            s.split(',').map(str::trim).collect::<Vec<_>>()
+        };
+        if parts.len() != SIZE {
+            return Err(ParseArrayError::WrongLength {
+                len: parts.len(),
+                size: SIZE,
+            });
+        }
+        let items = parts
+            .into_iter()
+            .map(Item::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ParseArrayError::Item)?;
+        match <[Item; SIZE]>::try_from(items) {
+            Ok(array) => Ok(Self(PhantomData, array)),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+/// Helper macro that creates an AndexableArray from an Andex
+///
+/// This macro just uses the Andex argument to figure out the array
+/// size, so that we don't have to repeat it here.
+///
+/// Example:
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {};
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// // Create the array wrapper with the macro:
+/// type MyU32 = andex_array!(MyIdx, u32);
+/// ```
+#[macro_export]
+macro_rules! andex_array {
+    ($andex: ty, $item: ty) => {
+        $crate::AndexableArray<$andex, $item, { <$andex>::SIZE }>
+    };
+}
+
+/// Helper macro that creates an AndexableArray value from literals
+///
+/// The first argument is the type of the array, and the others are
+/// the items. The macro can be used in `const` contexts, and it fails
+/// to compile if the number of items doesn't match the size of the
+/// array.
+///
+/// Example:
+/// ```
+/// use andex::*;
+///
+/// enum PieceMarker {}
+/// type Piece = Andex<PieceMarker, 3>;
+/// type PieceValues = andex_array!(Piece, u32);
+///
+/// const VALUES: PieceValues = andex_arr![PieceValues; 1, 3, 9];
+/// assert_eq!(VALUES[Piece::LAST], 9);
+/// ```
+///
+/// The following doesn't compile:
+/// ```compile_fail
+/// use andex::*;
+///
+/// enum PieceMarker {}
+/// type Piece = Andex<PieceMarker, 3>;
+/// type PieceValues = andex_array!(Piece, u32);
+///
+/// const VALUES: PieceValues = andex_arr![PieceValues; 1, 3];
+/// ```
+#[macro_export]
+macro_rules! andex_arr {
+    ($array: ty; $($item: expr),* $(,)?) => {
+        <$array>::from_array([$($item),*])
+    };
+}
+
+impl<A, Item, const SIZE: usize> AndexableArray<A, Item, SIZE> {
+    /// Creates the array wrapper in `const` contexts
+    ///
+    /// This is the `const` equivalent of `From<[Item; SIZE]>`, used by
+    /// [`andex_arr!`].
+    pub const fn from_array(array: [Item; SIZE]) -> Self {
+        AndexableArray(PhantomData, array)
+    }
+
+    /// Returns an iterator over the `&AndexableArray`.
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.1.iter()
+    }
+
+    /// Reinterprets a reference to an array as a reference to an
+    /// `AndexableArray`, without copying
+    pub fn from_array_ref(array: &[Item; SIZE]) -> &Self {
+        // Safe because AndexableArray is repr(transparent)
+        unsafe { &*(array as *const [Item; SIZE] as *const Self) }
+    }
+
+    /// Reinterprets a mutable reference to an array as a mutable
+    /// reference to an `AndexableArray`, without copying
+    pub fn from_array_mut(array: &mut [Item; SIZE]) -> &mut Self {
+        // Safe because AndexableArray is repr(transparent)
+        unsafe { &mut *(array as *mut [Item; SIZE] as *mut Self) }
+    }
+
+    /// Borrows the array as an array of `N`-item chunks, without
+    /// copying
+    ///
+    /// The chunks are indexed by their own andex type, with `CHUNKS`
+    /// values; `N * CHUNKS` must be equal to `SIZE`, which is checked
+    /// at compile time. These are usually inferred from the type of
+    /// the result.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 6>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// enum ChunkIdxMarker {}
+    /// type ChunkIdx = Andex<ChunkIdxMarker, 3>;
+    /// type MyChunks = andex_array!(ChunkIdx, [u32; 2]);
+    ///
+    /// let mut myu32 = MyU32::from([0, 1, 2, 3, 4, 5]);
+    /// let chunks: &MyChunks = myu32.as_chunks();
+    /// assert_eq!(chunks[ChunkIdx::LAST], [4, 5]);
+    /// let chunks: &mut MyChunks = myu32.as_chunks_mut();
+    /// chunks[ChunkIdx::FIRST] = [7, 7];
+    /// assert_eq!(myu32.as_ref(), &[7, 7, 2, 3, 4, 5]);
+    /// ```
+    ///
+    /// The sizes must match; the following doesn't compile:
+    ///
+    /// ```compile_fail
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 6>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// enum ChunkIdxMarker {}
+    /// type ChunkIdx = Andex<ChunkIdxMarker, 2>;
+    /// type MyChunks = andex_array!(ChunkIdx, [u32; 4]);
+    ///
+    /// let myu32 = MyU32::default();
+    /// let chunks: &MyChunks = myu32.as_chunks();
+    /// ```
+    pub fn as_chunks<const N: usize, C, const CHUNKS: usize>(
+        &self,
+    ) -> &AndexableArray<C, [Item; N], CHUNKS> {
+        const { assert!(N * CHUNKS == SIZE, "chunks must cover the whole array") };
+        // Safe because [Item; SIZE] has the same layout as
+        // [[Item; N]; CHUNKS], and AndexableArray is repr(transparent)
+        unsafe { &*(self as *const Self as *const AndexableArray<C, [Item; N], CHUNKS>) }
+    }
+
+    /// Borrows the array as a mutable array of `N`-item chunks,
+    /// without copying
+    ///
+    /// See [`Self::as_chunks`].
+    pub fn as_chunks_mut<const N: usize, C, const CHUNKS: usize>(
+        &mut self,
+    ) -> &mut AndexableArray<C, [Item; N], CHUNKS> {
+        const { assert!(N * CHUNKS == SIZE, "chunks must cover the whole array") };
+        // Safe because [Item; SIZE] has the same layout as
+        // [[Item; N]; CHUNKS], and AndexableArray is repr(transparent)
+        unsafe { &mut *(self as *mut Self as *mut AndexableArray<C, [Item; N], CHUNKS>) }
+    }
+
+    /// Repeats the array `K` times into a larger array
+    ///
+    /// The larger array is indexed by its own andex type, with `TOTAL`
+    /// values; `SIZE * K` must be equal to `TOTAL`, which is checked at
+    /// compile time. Its andexes can be split into the index of the
+    /// repetition and the index in the original array with
+    /// [`Andex::untile`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum ChannelMarker {}
+    /// type Channel = Andex<ChannelMarker, 2>;
+    /// type Gains = andex_array!(Channel, f32);
+    ///
+    /// enum FrameMarker {}
+    /// type Frame = Andex<FrameMarker, 3>;
+    ///
+    /// enum SampleMarker {}
+    /// type Sample = Andex<SampleMarker, 6>;
+    /// type SampleGains = andex_array!(Sample, f32);
+    ///
+    /// let gains = Gains::from([0.5, 1.0]);
+    /// let tiled: SampleGains = gains.tile::<3, _, 6>();
+    /// for sample in Sample::iter() {
+    ///     let (_frame, channel): (Frame, Channel) = sample.untile();
+    ///     assert_eq!(tiled[sample], gains[channel]);
+    /// }
+    /// ```
+    pub fn tile<const K: usize, T, const TOTAL: usize>(&self) -> AndexableArray<T, Item, TOTAL>
+    where
+        Item: Clone,
+    {
+        const { assert!(SIZE * K == TOTAL, "tiled size must be SIZE * K") };
+        AndexableArray(
+            PhantomData,
+            std::array::from_fn(|i| self.1[i % SIZE].clone()),
+        )
+    }
+
+    /// Splits the array in a head with `N` items and a tail with the
+    /// `REST` remaining ones
+    ///
+    /// Each part is indexed by its own andex type. `N + REST` must be
+    /// equal to `SIZE`, which is checked at compile time. These are
+    /// usually inferred from the types of the result.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum PacketIdxMarker {}
+    /// type PacketIdx = Andex<PacketIdxMarker, 6>;
+    /// type Packet = andex_array!(PacketIdx, u8);
+    ///
+    /// enum HeaderIdxMarker {}
+    /// type HeaderIdx = Andex<HeaderIdxMarker, 2>;
+    /// type Header = andex_array!(HeaderIdx, u8);
+    ///
+    /// enum PayloadIdxMarker {}
+    /// type PayloadIdx = Andex<PayloadIdxMarker, 4>;
+    /// type Payload = andex_array!(PayloadIdx, u8);
+    ///
+    /// let packet = Packet::from([1, 4, 5, 6, 7, 8]);
+    /// let (header, payload): (Header, Payload) = packet.split();
+    /// assert_eq!(header[HeaderIdx::LAST], 4);
+    /// assert_eq!(payload[PayloadIdx::FIRST], 5);
+    /// ```
+    pub fn split<const N: usize, H, R, const REST: usize>(
+        self,
+    ) -> (AndexableArray<H, Item, N>, AndexableArray<R, Item, REST>) {
+        const { assert!(N + REST == SIZE, "split parts must cover the whole array") };
+        let mut items = IntoIterator::into_iter(self.1);
+        let head = std::array::from_fn(|_| items.next().expect("head is within the array"));
+        let tail = std::array::from_fn(|_| items.next().expect("tail is within the array"));
+        (
+            AndexableArray(PhantomData, head),
+            AndexableArray(PhantomData, tail),
+        )
+    }
+
+    /// Borrows the array as a mutable head with `N` items and a
+    /// mutable tail with the `REST` remaining ones, without copying
+    ///
+    /// See [`Self::split`].
+    pub fn split_mut<const N: usize, H, R, const REST: usize>(
+        &mut self,
+    ) -> (
+        &mut AndexableArray<H, Item, N>,
+        &mut AndexableArray<R, Item, REST>,
+    ) {
+        const { assert!(N + REST == SIZE, "split parts must cover the whole array") };
+        let (head, tail) = self.1.split_at_mut(N);
+        (
+            AndexableArray::from_array_mut(head.try_into().expect("head has N items")),
+            AndexableArray::from_array_mut(tail.try_into().expect("tail has REST items")),
+        )
+    }
+
+    /// Borrows a slice of exactly `SIZE` items as an
+    /// `AndexableArray`, without copying
+    ///
+    /// Returns `None` if the length of the slice is not `SIZE`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 3>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let data = vec![1, 2, 3];
+    /// let view = MyU32::try_from_slice(&data).unwrap();
+    /// assert_eq!(view[MyIdx::LAST], 3);
+    /// ```
+    pub fn try_from_slice(slice: &[Item]) -> Option<&Self> {
+        <&[Item; SIZE]>::try_from(slice)
+            .ok()
+            .map(Self::from_array_ref)
+    }
+
+    /// Borrows a mutable slice of exactly `SIZE` items as an
+    /// `AndexableArray`, without copying
+    ///
+    /// Returns `None` if the length of the slice is not `SIZE`.
+    pub fn try_from_slice_mut(slice: &mut [Item]) -> Option<&mut Self> {
+        <&mut [Item; SIZE]>::try_from(slice)
+            .ok()
+            .map(Self::from_array_mut)
+    }
+}
+
+impl<M, Item, const SIZE: usize> AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Returns an iterator over the slots that differ between `self`
+    /// and `other`
+    ///
+    /// Each element is a tuple with the index, the item in `self` and
+    /// the item in `other`, in index order.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 4>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let old = MyU32::from([1, 2, 3, 4]);
+    /// let new = MyU32::from([1, 5, 3, 6]);
+    /// for (idx, o, n) in old.diff(&new) {
+    ///     println!("{}: {} -> {}", idx, o, n);
+    /// }
+    /// assert_eq!(old.diff(&new).count(), 2);
+    /// ```
+    pub fn diff<'a>(
+        &'a self,
+        other: &'a Self,
+    ) -> impl Iterator<Item = (Andex<M, SIZE>, &'a Item, &'a Item)>
+    where
+        Item: PartialEq,
+    {
+        Andex::<M, SIZE>::iter()
+            .zip(self.1.iter().zip(other.1.iter()))
+            .filter(|(_, (a, b))| a != b)
+            .map(|(i, (a, b))| (i, a, b))
+    }
+
+    /// Copies the items in the `src` range to the slots starting at
+    /// `dest`
+    ///
+    /// The ranges may overlap. This is the typed counterpart of
+    /// [`slice::copy_within`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` ends before it starts, or if the destination
+    /// range goes past the end of the array.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 5>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let mut myu32 = MyU32::from([1, 2, 3, 4, 5]);
+    /// myu32.copy_within(MyIdx::FIRST..MyIdx::new::<2>(), MyIdx::new::<3>());
+    /// assert_eq!(myu32.as_ref(), &[1, 2, 3, 1, 2]);
+    /// myu32.copy_within(MyIdx::new::<2>()..=MyIdx::LAST, MyIdx::FIRST);
+    /// assert_eq!(myu32.as_ref(), &[3, 1, 2, 1, 2]);
+    /// ```
+    pub fn copy_within<R>(&mut self, src: R, dest: Andex<M, SIZE>)
+    where
+        Item: Copy,
+        R: ops::RangeBounds<Andex<M, SIZE>>,
+    {
+        self.1.copy_within(
+            (
+                src.start_bound().map(usize::from),
+                src.end_bound().map(usize::from),
+            ),
+            usize::from(dest),
+        );
+    }
+
+    /// Copies the items of `src` to the slots starting at `start`
+    ///
+    /// Returns [`Error::OutOfBounds`] with the last slot that would be
+    /// written if `src` doesn't fit, in which case the array is not
+    /// modified.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 5>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let mut myu32 = MyU32::default();
+    /// myu32.copy_from(MyIdx::new::<3>(), &[7, 8])?;
+    /// assert_eq!(myu32.as_ref(), &[0, 0, 0, 7, 8]);
+    /// assert!(myu32.copy_from(MyIdx::new::<4>(), &[7, 8]).is_err());
+    /// # Ok::<(), andex::Error>(())
+    /// ```
+    pub fn copy_from(&mut self, start: Andex<M, SIZE>, src: &[Item]) -> Result<(), Error>
+    where
+        Item: Copy,
+    {
+        let start = usize::from(start);
+        let end = start + src.len();
+        if end > SIZE {
+            return Err(Error::OutOfBounds {
+                value: end - 1,
+                size: SIZE,
+            });
+        }
+        self.1[start..end].copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Writes the items yielded by the iterator to the first slots
+    ///
+    /// Stops when the iterator ends or when the array is full, so
+    /// that items past `SIZE` are not consumed. Returns the andex of
+    /// the first slot that was not written, or `None` if all of them
+    /// were.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 4>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let mut myu32 = MyU32::default();
+    /// assert_eq!(myu32.fill_prefix(vec![7, 8]), Some(MyIdx::new::<2>()));
+    /// assert_eq!(myu32.as_ref(), &[7, 8, 0, 0]);
+    /// assert_eq!(myu32.fill_prefix(1..), None);
+    /// assert_eq!(myu32.as_ref(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn fill_prefix<I>(&mut self, iter: I) -> Option<Andex<M, SIZE>>
+    where
+        I: IntoIterator<Item = Item>,
+    {
+        let mut written = 0_usize;
+        for (slot, item) in self.1.iter_mut().zip(iter) {
+            *slot = item;
+            written += 1;
+        }
+        Andex::try_from(written).ok()
+    }
 
-impl<M, const SIZE: usize> PartialEq for Andex<M, SIZE> {
-    fn eq(&self, other: &Self) -> bool {
-        self.1 == other.1
+    /// Swaps the items of the array with the items of the slice
+    ///
+    /// Returns [`Error::WrongLength`] if the length of the slice is
+    /// not `SIZE`, in which case nothing is swapped.
+    pub fn swap_with_slice(&mut self, other: &mut [Item]) -> Result<(), Error> {
+        if other.len() != SIZE {
+            return Err(Error::WrongLength {
+                len: other.len(),
+                size: SIZE,
+            });
+        }
+        self.1.swap_with_slice(other);
+        Ok(())
     }
-}
-
-impl<M, const SIZE: usize> Eq for Andex<M, SIZE> {}
 
-impl<M, const SIZE: usize> PartialOrd for Andex<M, SIZE> {
-    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        Some(self.cmp(other))
+    /// Returns the index of the first item for which `pred` returns
+    /// false, or `None` if it returns true for all of them
+    ///
+    /// The array must be partitioned according to `pred`, with all
+    /// the items for which it returns true first. Uses a binary
+    /// search, like [`slice::partition_point`].
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 5>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let myu32 = MyU32::from([1, 2, 4, 8, 16]);
+    /// assert_eq!(myu32.partition_point(|&x| x < 5), Some(MyIdx::new::<3>()));
+    /// assert_eq!(myu32.partition_point(|&x| x < 20), None);
+    /// ```
+    pub fn partition_point<P>(&self, pred: P) -> Option<Andex<M, SIZE>>
+    where
+        P: FnMut(&Item) -> bool,
+    {
+        Andex::try_from(self.1.partition_point(pred)).ok()
     }
-}
 
-impl<M, const SIZE: usize> Ord for Andex<M, SIZE> {
-    fn cmp(&self, other: &Self) -> cmp::Ordering {
-        self.1.cmp(&other.1)
+    /// Returns the index of the first item that is not less than
+    /// `value`, or `None` if there is no such item
+    ///
+    /// The array must be sorted.
+    pub fn lower_bound(&self, value: &Item) -> Option<Andex<M, SIZE>>
+    where
+        Item: Ord,
+    {
+        self.partition_point(|item| item < value)
     }
-}
 
-impl<M, const SIZE: usize> From<Andex<M, SIZE>> for usize {
-    fn from(andex: Andex<M, SIZE>) -> Self {
-        andex.1
+    /// Returns the index of the first item that is greater than
+    /// `value`, or `None` if there is no such item
+    ///
+    /// The array must be sorted.
+    pub fn upper_bound(&self, value: &Item) -> Option<Andex<M, SIZE>>
+    where
+        Item: Ord,
+    {
+        self.partition_point(|item| item <= value)
     }
-}
 
-impl<M, const SIZE: usize> From<&Andex<M, SIZE>> for usize {
-    fn from(andex: &Andex<M, SIZE>) -> Self {
-        andex.1
+    /// Returns true if the predicate holds for any index and item
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 4>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let myu32 = MyU32::from([3, 1, 4, 1]);
+    /// assert!(myu32.any(|idx, &item| idx == MyIdx::LAST && item == 1));
+    /// assert!(myu32.all(|_, &item| item > 0));
+    /// assert_eq!(myu32.count_matching(|_, &item| item == 1), 2);
+    /// assert_eq!(
+    ///     myu32.find_map_index(|idx, &item| (item == 4).then_some(idx)),
+    ///     Some(MyIdx::new::<2>())
+    /// );
+    /// ```
+    pub fn any<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(Andex<M, SIZE>, &Item) -> bool,
+    {
+        Andex::<M, SIZE>::iter()
+            .zip(self.1.iter())
+            .any(|(andex, item)| pred(andex, item))
     }
-}
 
-impl<M, const SIZE: usize> convert::TryFrom<usize> for Andex<M, SIZE> {
-    type Error = Error;
-    fn try_from(value: usize) -> Result<Self, Self::Error> {
-        if value < SIZE {
-            Ok(Andex(PhantomData, value))
-        } else {
-            Err(Error::OutOfBounds { value, size: SIZE })
-        }
+    /// Returns true if the predicate holds for all indexes and items
+    pub fn all<F>(&self, mut pred: F) -> bool
+    where
+        F: FnMut(Andex<M, SIZE>, &Item) -> bool,
+    {
+        Andex::<M, SIZE>::iter()
+            .zip(self.1.iter())
+            .all(|(andex, item)| pred(andex, item))
     }
-}
 
-impl<M, const SIZE: usize> fmt::Debug for Andex<M, SIZE> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", usize::from(self))
+    /// Returns the number of indexes and items for which the
+    /// predicate holds
+    pub fn count_matching<F>(&self, mut pred: F) -> usize
+    where
+        F: FnMut(Andex<M, SIZE>, &Item) -> bool,
+    {
+        Andex::<M, SIZE>::iter()
+            .zip(self.1.iter())
+            .filter(|(andex, item)| pred(*andex, item))
+            .count()
     }
-}
 
-impl<M, const SIZE: usize> fmt::Display for Andex<M, SIZE> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", usize::from(self))
+    /// Returns the first non-`None` result of the function applied to
+    /// the indexes and items, in index order
+    pub fn find_map_index<B, F>(&self, mut f: F) -> Option<B>
+    where
+        F: FnMut(Andex<M, SIZE>, &Item) -> Option<B>,
+    {
+        Andex::<M, SIZE>::iter()
+            .zip(self.1.iter())
+            .find_map(|(andex, item)| f(andex, item))
     }
-}
-
-impl<M, const SIZE: usize> str::FromStr for Andex<M, SIZE> {
-    type Err = Error;
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::try_from(usize::from_str(s)?)
+    /// Returns an iterator over the runs of consecutive equal items
+    ///
+    /// Each element is a tuple with the index where the run starts,
+    /// its length and the item.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 6>;
+    /// type MyChar = andex_array!(MyIdx, char);
+    ///
+    /// let row = MyChar::from(['a', 'a', 'b', 'c', 'c', 'c']);
+    /// for (start, len, item) in row.iter_runs() {
+    ///     println!("{} x {} starting at {}", len, item, start);
+    /// }
+    /// assert_eq!(row.iter_runs().count(), 3);
+    /// ```
+    pub fn iter_runs(&self) -> impl Iterator<Item = (Andex<M, SIZE>, usize, &Item)>
+    where
+        Item: PartialEq,
+    {
+        let mut start = 0;
+        self.1.chunk_by(|a, b| a == b).map(move |run| {
+            let andex = Andex::new_unchecked(start);
+            start += run.len();
+            (andex, run.len(), &run[0])
+        })
     }
 }
 
-/* Iterator */
-
-/// Iterator for Andex instances
+/// Slot-by-slot initialization
 ///
-/// This is the type returned by Andex::<_,_>::iter().
-/// There's no reason to use it directly.
+/// These methods allow us to initialize an array one slot at a time,
+/// for instance while reading it from I/O, without requiring
+/// `Default` or an intermediate copy.
 ///
-/// Iterating example:
+/// Example:
 ///
 /// ```
+/// use std::mem::MaybeUninit;
 /// use andex::*;
 ///
-/// pub struct PlayerIdMarker;
-/// type PlayerId = Andex<PlayerIdMarker, 12>;
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+/// type MyStrings = andex_array!(MyIdx, MaybeUninit<String>);
 ///
-/// for i in PlayerId::iter() {
-///     println!("{}", i);
+/// let mut array = MyStrings::uninit();
+/// for i in MyIdx::iter() {
+///     array.write(i, i.to_string());
 /// }
+/// // Safe because all slots were written above
+/// let array = unsafe { array.assume_init() };
+/// assert_eq!(array[MyIdx::LAST], "2");
 /// ```
-pub struct AndexIterator<M, const SIZE: usize>(Option<Andex<M, SIZE>>);
+impl<A, Item, const SIZE: usize> AndexableArray<A, MaybeUninit<Item>, SIZE> {
+    /// Creates an array with all slots uninitialized
+    pub const fn uninit() -> Self {
+        AndexableArray(PhantomData, [const { MaybeUninit::uninit() }; SIZE])
+    }
 
-impl<M, const SIZE: usize> fmt::Debug for AndexIterator<M, SIZE> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "AndexIterator({:?})", self.0)
+    /// Converts the array to an array of initialized items
+    ///
+    /// # Safety
+    ///
+    /// All slots must have been initialized, see
+    /// [`MaybeUninit::assume_init`]. The items of the array are not
+    /// dropped if this is not called.
+    pub unsafe fn assume_init(self) -> AndexableArray<A, Item, SIZE> {
+        // MaybeUninit<Item> has the same layout as Item, and
+        // AndexableArray is repr(transparent)
+        unsafe { ptr::read(&self as *const Self as *const AndexableArray<A, Item, SIZE>) }
     }
 }
 
-impl<M, const SIZE: usize> Default for AndexIterator<M, SIZE> {
-    fn default() -> Self {
-        AndexIterator(Some(Andex::<M, SIZE>::default()))
+impl<M, Item, const SIZE: usize> AndexableArray<Andex<M, SIZE>, MaybeUninit<Item>, SIZE> {
+    /// Initializes the slot with the provided value, returning a
+    /// mutable reference to it
+    ///
+    /// The previous value of the slot is overwritten without being
+    /// dropped.
+    pub fn write(&mut self, index: Andex<M, SIZE>, value: Item) -> &mut Item {
+        self[index].write(value)
     }
 }
 
-impl<M, const SIZE: usize> Iterator for AndexIterator<M, SIZE> {
-    type Item = Andex<M, SIZE>;
-    fn next(&mut self) -> Option<Self::Item> {
-        if let Some(i) = self.0.take() {
-            self.0 = i.next();
-            Some(i)
-        } else {
-            None
-        }
+impl<A, Item: Clone, const SIZE: usize> Clone for AndexableArray<A, Item, SIZE> {
+    fn clone(&self) -> Self {
+        AndexableArray(PhantomData, self.1.clone())
     }
 }
 
-/* Array wrapper */
-
-/// Array wrapper indexable by the provided Andex type.
-///
-/// Example:
-///
-/// ```
-/// use andex::*;
-///
-/// enum MyIdxMarker {}
-/// type MyIdx = Andex<MyIdxMarker, 12>;
-///
-/// // Create the array wrapper:
-/// type MyU32 = AndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
-///
-/// // We can create other arrays with the same Andex type:
-/// type MyF64 = AndexableArray<MyIdx, f64, { MyIdx::SIZE }>;
-///
-/// // Create a default array:
-/// let myu32 = MyU32::default();
-/// // Print the first element:
-/// const first : MyIdx = MyIdx::new::<0>();
-/// println!("{:?}", myu32[first]);
-/// // Iterate and print all elements:
-/// for i in MyIdx::iter() {
-///     println!("{:?}", myu32[i]);
-/// }
-/// // Print the whole array
-/// println!("{:?}", myu32);
-/// ```
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct AndexableArray<A, Item, const SIZE: usize>(PhantomData<A>, [Item; SIZE]);
+impl<A, Item: Copy, const SIZE: usize> Copy for AndexableArray<A, Item, SIZE> {}
 
-impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for AndexableArray<A, Item, SIZE> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(
-            f,
-            "AndexableArray<{}>({:?})",
-            std::any::type_name::<Item>(),
-            self.1
-        )
+impl<A, Item: PartialEq<U>, U, const SIZE: usize> PartialEq<[U; SIZE]>
+    for AndexableArray<A, Item, SIZE>
+{
+    fn eq(&self, other: &[U; SIZE]) -> bool {
+        self.1 == *other
     }
 }
 
-/// Helper macro that creates an AndexableArray from an Andex
-///
-/// This macro just uses the Andex argument to figure out the array
-/// size, so that we don't have to repeat it here.
-///
-/// Example:
-/// ```
-/// use andex::*;
-///
-/// enum MyIdxMarker {};
-/// type MyIdx = Andex<MyIdxMarker, 12>;
-///
-/// // Create the array wrapper with the macro:
-/// type MyU32 = andex_array!(MyIdx, u32);
-/// ```
-#[macro_export]
-macro_rules! andex_array {
-    ($andex: ty, $item: ty) => {
-        $crate::AndexableArray<$andex, $item, { <$andex>::SIZE }>
-    };
+impl<A, Item: PartialEq<U>, U, const SIZE: usize> PartialEq<[U]> for AndexableArray<A, Item, SIZE> {
+    fn eq(&self, other: &[U]) -> bool {
+        self.1 == *other
+    }
 }
 
-impl<A, Item, const SIZE: usize> AndexableArray<A, Item, SIZE> {
-    /// Returns an iterator over the `&AndexableArray`.
-    pub fn iter(&self) -> impl Iterator<Item = &Item> {
-        self.1.iter()
+impl<A, Item: PartialEq<U>, U, const SIZE: usize> PartialEq<&[U]>
+    for AndexableArray<A, Item, SIZE>
+{
+    fn eq(&self, other: &&[U]) -> bool {
+        self.1 == **other
     }
 }
 
-impl<A, Item: Copy, const SIZE: usize> Clone for AndexableArray<A, Item, SIZE> {
-    fn clone(&self) -> Self {
-        *self
+impl<A, Item, U: PartialEq<Item>, const SIZE: usize> PartialEq<AndexableArray<A, Item, SIZE>>
+    for [U; SIZE]
+{
+    fn eq(&self, other: &AndexableArray<A, Item, SIZE>) -> bool {
+        *self == other.1
     }
 }
 
-impl<A, Item: Copy, const SIZE: usize> Copy for AndexableArray<A, Item, SIZE> {}
-
-impl<A, Item: Default + Copy, const SIZE: usize> Default for AndexableArray<A, Item, SIZE> {
-    fn default() -> Self {
-        AndexableArray(Default::default(), [Default::default(); SIZE])
+impl<A, Item, U: PartialEq<Item>, const SIZE: usize> PartialEq<AndexableArray<A, Item, SIZE>>
+    for [U]
+{
+    fn eq(&self, other: &AndexableArray<A, Item, SIZE>) -> bool {
+        *self == other.1
     }
 }
 
-impl<A, Item, const SIZE: usize> ops::Index<Andex<A, SIZE>>
-    for AndexableArray<Andex<A, SIZE>, Item, SIZE>
+impl<A, Item, U: PartialEq<Item>, const SIZE: usize> PartialEq<AndexableArray<A, Item, SIZE>>
+    for &[U]
 {
-    type Output = Item;
-    fn index(&self, index: Andex<A, SIZE>) -> &Self::Output {
-        index.index_arr(&self.1)
+    fn eq(&self, other: &AndexableArray<A, Item, SIZE>) -> bool {
+        **self == other.1
     }
 }
 
-impl<A, Item, const SIZE: usize> ops::IndexMut<Andex<A, SIZE>>
-    for AndexableArray<Andex<A, SIZE>, Item, SIZE>
-{
-    fn index_mut(&mut self, index: Andex<A, SIZE>) -> &mut Item {
-        index.index_arr_mut(&mut self.1)
+impl<A, Item: Default + Copy, const SIZE: usize> Default for AndexableArray<A, Item, SIZE> {
+    fn default() -> Self {
+        AndexableArray(Default::default(), [Default::default(); SIZE])
     }
 }
 
-impl<A, Item, const SIZE: usize> ops::Index<&Andex<A, SIZE>>
-    for AndexableArray<Andex<A, SIZE>, Item, SIZE>
+impl<A, I, M, Item, const SIZE: usize> ops::Index<I> for AndexableArray<A, Item, SIZE>
+where
+    A: AndexLike<Andex = Andex<M, SIZE>>,
+    I: AndexLike<Andex = Andex<M, SIZE>>,
 {
     type Output = Item;
-    fn index(&self, index: &Andex<A, SIZE>) -> &Self::Output {
-        index.index_arr(&self.1)
+    fn index(&self, index: I) -> &Self::Output {
+        index.andex().index_arr(&self.1)
     }
 }
 
-impl<A, Item, const SIZE: usize> ops::IndexMut<&Andex<A, SIZE>>
-    for AndexableArray<Andex<A, SIZE>, Item, SIZE>
+impl<A, I, M, Item, const SIZE: usize> ops::IndexMut<I> for AndexableArray<A, Item, SIZE>
+where
+    A: AndexLike<Andex = Andex<M, SIZE>>,
+    I: AndexLike<Andex = Andex<M, SIZE>>,
 {
-    fn index_mut(&mut self, index: &Andex<A, SIZE>) -> &mut Item {
-        index.index_arr_mut(&mut self.1)
+    fn index_mut(&mut self, index: I) -> &mut Item {
+        index.andex().index_arr_mut(&mut self.1)
     }
 }
 
@@ -478,47 +2490,102 @@ impl<'a, A, Item, const SIZE: usize> IntoIterator for &'a mut AndexableArray<A,
     }
 }
 
+/// Collects exactly `SIZE` items
+///
+/// Panics if the iterator doesn't yield exactly `SIZE` items; the
+/// items collected so far are dropped properly in that case.
 impl<A, Item, const SIZE: usize> core::iter::FromIterator<Item> for AndexableArray<A, Item, SIZE> {
     fn from_iter<I: core::iter::IntoIterator<Item = Item>>(intoiter: I) -> Self {
-        let mut andexable = AndexableArray::<A, MaybeUninit<Item>, SIZE>(PhantomData, unsafe {
-            std::mem::MaybeUninit::uninit().assume_init()
-        });
         let mut iter = intoiter.into_iter();
-        for item in &mut andexable {
-            if let Some(fromiter) = iter.next() {
-                item.write(fromiter);
-            } else {
-                panic!("iterator too short for andexable type");
-            }
-        }
+        let array =
+            std::array::from_fn(|_| iter.next().expect("iterator too short for andexable type"));
         if iter.next().is_some() {
             panic!("iterator too long for andexable type");
         }
-
-        unsafe { std::mem::transmute_copy::<_, AndexableArray<A, Item, SIZE>>(&andexable) }
+        AndexableArray(PhantomData, array)
     }
 }
 
+/// Collects exactly `SIZE` copied items
+///
+/// Panics if the iterator doesn't yield exactly `SIZE` items.
 impl<'a, A, Item: 'a + Copy, const SIZE: usize> core::iter::FromIterator<&'a Item>
     for AndexableArray<A, Item, SIZE>
 {
     fn from_iter<I: core::iter::IntoIterator<Item = &'a Item>>(intoiter: I) -> Self {
-        let mut andexable = AndexableArray::<A, MaybeUninit<Item>, SIZE>(PhantomData, unsafe {
-            std::mem::MaybeUninit::uninit().assume_init()
-        });
-        let mut iter = intoiter.into_iter();
-        for item in &mut andexable {
-            if let Some(&fromiter) = iter.next() {
-                item.write(fromiter);
-            } else {
-                panic!("iterator too short for andexable type");
+        intoiter.into_iter().copied().collect()
+    }
+}
+
+/// Elementwise sum of arrays
+///
+/// The sum of an empty iterator is the array with the sum of no
+/// items in all slots, which is zero for the numeric types.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+/// type MyI32 = andex_array!(MyIdx, i32);
+///
+/// let deltas = vec![MyI32::from([1, 2, 3]), MyI32::from([-1, 0, 1])];
+/// let total = deltas.into_iter().sum::<MyI32>();
+/// assert_eq!(total.as_ref(), &[0, 2, 4]);
+/// ```
+impl<A, Item, const SIZE: usize> iter::Sum for AndexableArray<A, Item, SIZE>
+where
+    Item: iter::Sum + ops::AddAssign,
+{
+    fn sum<I: Iterator<Item = Self>>(arrays: I) -> Self {
+        let mut total = std::array::from_fn(|_| iter::empty::<Item>().sum());
+        for array in arrays {
+            for (t, item) in total.iter_mut().zip(array.1) {
+                *t += item;
             }
         }
-        if iter.next().is_some() {
-            panic!("iterator too long for andexable type");
+        AndexableArray(PhantomData, total)
+    }
+}
+
+impl<'a, A, Item, const SIZE: usize> iter::Sum<&'a AndexableArray<A, Item, SIZE>>
+    for AndexableArray<A, Item, SIZE>
+where
+    Item: 'a + Copy + iter::Sum + ops::AddAssign,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(arrays: I) -> Self {
+        arrays.copied().sum()
+    }
+}
+
+/// Elementwise product of arrays
+///
+/// The product of an empty iterator is the array with the product of
+/// no items in all slots, which is one for the numeric types.
+impl<A, Item, const SIZE: usize> iter::Product for AndexableArray<A, Item, SIZE>
+where
+    Item: iter::Product + ops::MulAssign,
+{
+    fn product<I: Iterator<Item = Self>>(arrays: I) -> Self {
+        let mut total = std::array::from_fn(|_| iter::empty::<Item>().product());
+        for array in arrays {
+            for (t, item) in total.iter_mut().zip(array.1) {
+                *t *= item;
+            }
         }
+        AndexableArray(PhantomData, total)
+    }
+}
 
-        unsafe { std::mem::transmute_copy::<_, AndexableArray<A, Item, SIZE>>(&andexable) }
+impl<'a, A, Item, const SIZE: usize> iter::Product<&'a AndexableArray<A, Item, SIZE>>
+    for AndexableArray<A, Item, SIZE>
+where
+    Item: 'a + Copy + iter::Product + ops::MulAssign,
+{
+    fn product<I: Iterator<Item = &'a Self>>(arrays: I) -> Self {
+        arrays.copied().product()
     }
 }
 
@@ -551,8 +2618,35 @@ pub enum Error {
         /// The maximum value accepted is `SIZE - 1`
         size: usize,
     },
+    /// Tried to use a value outside of the `min..=max` range to create
+    /// an index that doesn't start at zero, like an `OffsetAndex`
+    OutOfRange {
+        /// The out-of-range value that was provided at index creation
+        value: usize,
+        /// The smallest valid value
+        min: usize,
+        /// The largest valid value
+        max: usize,
+    },
+    /// Tried to create an andex from a negative value, or from a value
+    /// that doesn't fit in an `usize`
+    NotRepresentable {
+        /// The value that was provided at andex creation
+        value: i128,
+        /// The `SIZE` of the andex type
+        size: usize,
+    },
     /// Underlying ParseIntError from integer parsing
     ParseIntError(num::ParseIntError),
+    /// Tried to parse an andex from a name that is not known
+    UnknownName(String),
+    /// A slice doesn't have the same length as the array
+    WrongLength {
+        /// The length of the slice
+        len: usize,
+        /// The `SIZE` of the array
+        size: usize,
+    },
 }
 
 impl error::Error for Error {}
@@ -571,10 +2665,59 @@ impl fmt::Display for Error {
                 ref size,
             } => write!(
                 f,
-                "value {} is out-of-bounds for index with size {}",
-                value, size
+                "value {} is out-of-bounds for index with size {}, valid values are 0 to {}",
+                value,
+                size,
+                size.saturating_sub(1)
+            ),
+            Error::OutOfRange { value, min, max } => write!(
+                f,
+                "value {} is out-of-bounds, valid values are {} to {}",
+                value, min, max
+            ),
+            Error::NotRepresentable { value, size } => write!(
+                f,
+                "value {} is out-of-bounds for index with size {}, valid values are 0 to {}",
+                value,
+                size,
+                size.saturating_sub(1)
+            ),
+            Error::ParseIntError(err) => write!(f, "invalid andex value: {}", err),
+            Error::UnknownName(name) => write!(f, "unknown andex name {:?}", name),
+            Error::WrongLength { len, size } => write!(
+                f,
+                "slice with length {} doesn't match array with size {}",
+                len, size
+            ),
+        }
+    }
+}
+
+/// Error parsing an [`AndexableArray`] from its text representation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseArrayError<E> {
+    /// The number of items is not the `SIZE` of the array
+    WrongLength {
+        /// The number of items found
+        len: usize,
+        /// The `SIZE` of the array
+        size: usize,
+    },
+    /// Underlying error from parsing an item
+    Item(E),
+}
+
+impl<E: fmt::Debug + fmt::Display> error::Error for ParseArrayError<E> {}
+
+impl<E: fmt::Display> fmt::Display for ParseArrayError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseArrayError::WrongLength { len, size } => write!(
+                f,
+                "found {} items while parsing array with size {}",
+                len, size
             ),
-            Error::ParseIntError(err) => write!(f, "{}", err),
+            ParseArrayError::Item(err) => write!(f, "{}", err),
         }
     }
 }
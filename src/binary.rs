@@ -0,0 +1,122 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! binary module
+//!
+//! Binary streaming of andexable arrays of plain numeric items.
+
+use std::convert::TryFrom;
+use std::io;
+
+use crate::andex::*;
+
+/// Byte order used by the binary streaming functions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Least significant byte first
+    Little,
+    /// Most significant byte first
+    Big,
+}
+
+/// Item that can be streamed in binary form
+///
+/// Implemented for all primitive integer and floating point types,
+/// which are streamed with their fixed size in the provided
+/// [`Endian`] byte order. `usize` and `isize` are always streamed as
+/// 64-bit integers, so that the format doesn't depend on the host;
+/// reading values that don't fit in them returns an error of kind
+/// [`io::ErrorKind::InvalidData`].
+pub trait BinaryItem: Sized {
+    /// Writes the item to the provided writer
+    fn write_item<W: io::Write>(&self, writer: &mut W, endian: Endian) -> io::Result<()>;
+    /// Reads an item from the provided reader
+    fn read_item<R: io::Read>(reader: &mut R, endian: Endian) -> io::Result<Self>;
+}
+
+macro_rules! binary_item_impl {
+    ($($t:ty),*) => {
+        $(
+            impl BinaryItem for $t {
+                fn write_item<W: io::Write>(&self, writer: &mut W, endian: Endian) -> io::Result<()> {
+                    match endian {
+                        Endian::Little => writer.write_all(&self.to_le_bytes()),
+                        Endian::Big => writer.write_all(&self.to_be_bytes()),
+                    }
+                }
+                fn read_item<R: io::Read>(reader: &mut R, endian: Endian) -> io::Result<Self> {
+                    let mut bytes = [0_u8; std::mem::size_of::<$t>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(match endian {
+                        Endian::Little => <$t>::from_le_bytes(bytes),
+                        Endian::Big => <$t>::from_be_bytes(bytes),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+binary_item_impl!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+macro_rules! binary_item_wide_impl {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl BinaryItem for $t {
+                fn write_item<W: io::Write>(&self, writer: &mut W, endian: Endian) -> io::Result<()> {
+                    (*self as $wide).write_item(writer, endian)
+                }
+                fn read_item<R: io::Read>(reader: &mut R, endian: Endian) -> io::Result<Self> {
+                    let value = <$wide>::read_item(reader, endian)?;
+                    <$t>::try_from(value).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+                }
+            }
+        )*
+    };
+}
+
+binary_item_wide_impl!(usize => u64, isize => i64);
+
+impl<A, Item: BinaryItem, const SIZE: usize> AndexableArray<A, Item, SIZE> {
+    /// Writes all items to the provided writer, in index order
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 3>;
+    /// type MyU16 = andex_array!(MyIdx, u16);
+    ///
+    /// let myu16 = MyU16::from([1, 2, 0x304]);
+    /// let mut bytes = Vec::new();
+    /// myu16.write_to(&mut bytes, Endian::Big)?;
+    /// assert_eq!(bytes, vec![0, 1, 0, 2, 3, 4]);
+    /// assert_eq!(MyU16::read_from(&bytes[..], Endian::Big)?, myu16);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn write_to<W: io::Write>(&self, mut writer: W, endian: Endian) -> io::Result<()> {
+        for item in self {
+            item.write_item(&mut writer, endian)?;
+        }
+        Ok(())
+    }
+
+    /// Reads exactly `SIZE` items from the provided reader
+    ///
+    /// Returns an error of kind [`io::ErrorKind::UnexpectedEof`] if
+    /// the reader ends before all items are read.
+    pub fn read_from<R: io::Read>(mut reader: R, endian: Endian) -> io::Result<Self> {
+        let items = (0..SIZE)
+            .map(|_| Item::read_item(&mut reader, endian))
+            .collect::<io::Result<Vec<_>>>()?;
+        <[Item; SIZE]>::try_from(items)
+            .map(AndexableArray::from)
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "wrong number of items"))
+    }
+}
@@ -0,0 +1,421 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! bucket_grid module
+//!
+//! 2D grid of cells indexed by an [`Andex`], each cell holding a
+//! bucket of items.
+
+use std::array;
+use std::convert::TryFrom;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops;
+use std::vec;
+
+use crate::andex::*;
+
+/// Grid of buckets indexed by a cell [`Andex`]
+///
+/// The grid has `WIDTH` columns and `SIZE / WIDTH` rows; `SIZE` must
+/// be a multiple of `WIDTH`, which is checked at compile time. Cells
+/// are indexed in row-major order by `Andex<M, SIZE>`, and each one of
+/// them holds a `Vec` of items.
+///
+/// This is the structure usually used for broad-phase collision
+/// binning.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum CellMarker {}
+/// type Cell = Andex<CellMarker, 12>;
+///
+/// // 4 columns by 3 rows:
+/// type Grid = BucketGrid<CellMarker, &'static str, 4, { Cell::SIZE }>;
+///
+/// let mut grid = Grid::new();
+/// let cell = grid.insert_at(1, 2, "ball").unwrap();
+/// assert_eq!(grid.position(cell), (1, 2));
+/// assert_eq!(grid.neighborhood(cell).count(), 6);
+/// assert_eq!(grid.drain(cell).collect::<Vec<_>>(), vec!["ball"]);
+/// ```
+pub struct BucketGrid<M, T, const WIDTH: usize, const SIZE: usize>(
+    AndexableArray<Andex<M, SIZE>, Vec<T>, SIZE>,
+);
+
+impl<M, T, const WIDTH: usize, const SIZE: usize> BucketGrid<M, T, WIDTH, SIZE> {
+    /// The number of columns of the grid
+    pub const WIDTH: usize = WIDTH;
+
+    /// The number of rows of the grid
+    pub const HEIGHT: usize = {
+        assert!(
+            WIDTH > 0 && SIZE.is_multiple_of(WIDTH),
+            "BucketGrid SIZE must be a multiple of WIDTH"
+        );
+        SIZE / WIDTH
+    };
+
+    /// Create a new grid with all buckets empty
+    pub fn new() -> Self {
+        let _ = Self::HEIGHT;
+        BucketGrid(AndexableArray::from(array::from_fn(|_| Vec::new())))
+    }
+
+    /// Returns the cell at the provided column and row, or `None` if
+    /// it's outside of the grid
+    pub fn cell_at(&self, x: usize, y: usize) -> Option<Andex<M, SIZE>> {
+        if x < WIDTH && y < Self::HEIGHT {
+            Andex::try_from(y * WIDTH + x).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns the column and row of the provided cell
+    pub fn position(&self, cell: Andex<M, SIZE>) -> (usize, usize) {
+        let i = usize::from(cell);
+        (i % WIDTH, i / WIDTH)
+    }
+
+    /// Inserts the item in the bucket of the provided cell
+    pub fn insert(&mut self, cell: Andex<M, SIZE>, item: T) {
+        self.0[cell].push(item);
+    }
+
+    /// Inserts the item in the bucket at the provided column and row
+    ///
+    /// Returns the cell where the item was inserted, or `None` if the
+    /// position is outside of the grid, in which case the item is
+    /// dropped.
+    pub fn insert_at(&mut self, x: usize, y: usize, item: T) -> Option<Andex<M, SIZE>> {
+        let cell = self.cell_at(x, y)?;
+        self.insert(cell, item);
+        Some(cell)
+    }
+
+    /// Returns the bucket of the provided cell
+    pub fn bucket(&self, cell: Andex<M, SIZE>) -> &[T] {
+        &self.0[cell]
+    }
+
+    /// Returns the mutable bucket of the provided cell
+    pub fn bucket_mut(&mut self, cell: Andex<M, SIZE>) -> &mut Vec<T> {
+        &mut self.0[cell]
+    }
+
+    /// Removes all items from the bucket of the provided cell,
+    /// returning them in an iterator
+    pub fn drain(&mut self, cell: Andex<M, SIZE>) -> vec::Drain<'_, T> {
+        self.0[cell].drain(..)
+    }
+
+    /// Removes all items from all buckets
+    pub fn clear(&mut self) {
+        for bucket in &mut self.0 {
+            bucket.clear();
+        }
+    }
+
+    /// Iterates over the cells in the 3x3 neighborhood of the provided
+    /// cell, including the cell itself, skipping the ones outside of
+    /// the grid
+    pub fn neighborhood(&self, cell: Andex<M, SIZE>) -> impl Iterator<Item = Andex<M, SIZE>> {
+        let (x, y) = self.position(cell);
+        let height = Self::HEIGHT;
+        (y.saturating_sub(1)..(y + 2).min(height)).flat_map(move |ny| {
+            (x.saturating_sub(1)..(x + 2).min(WIDTH))
+                .filter_map(move |nx| Andex::try_from(ny * WIDTH + nx).ok())
+        })
+    }
+
+    /// Iterates over all cells and the corresponding buckets
+    pub fn iter(&self) -> impl Iterator<Item = (Andex<M, SIZE>, &[T])> {
+        Andex::<M, SIZE>::iter().zip(self.0.iter().map(Vec::as_slice))
+    }
+}
+
+impl<M, T, const WIDTH: usize, const SIZE: usize> BucketGrid<M, T, WIDTH, SIZE> {
+    /// Iterates over the `TW`x`TH` tiles of the grid
+    ///
+    /// Tiles are yielded in row-major order, each one with its tile
+    /// andex and a [`GridTile`] view that is indexed by a within-tile
+    /// andex, also in row-major order. The grid must be evenly divided
+    /// in tiles, and the sizes of the andex types must match, which is
+    /// checked at compile time. The andex types and their sizes are
+    /// usually inferred.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum CellMarker {}
+    /// type Cell = Andex<CellMarker, 16>;
+    /// type Grid = BucketGrid<CellMarker, u32, 4, { Cell::SIZE }>;
+    ///
+    /// // 2x2 tiles of 2x2 cells each:
+    /// enum TileMarker {}
+    /// type Tile = Andex<TileMarker, 4>;
+    /// enum InTileMarker {}
+    /// type InTile = Andex<InTileMarker, 4>;
+    ///
+    /// let mut grid = Grid::new();
+    /// grid.insert_at(3, 2, 7);
+    /// for (tile, view) in grid.tiles::<2, 2, TileMarker, 4, InTileMarker, 4>() {
+    ///     let count = InTile::iter().map(|i| view[i].len()).sum::<usize>();
+    ///     assert_eq!(count, if tile == Tile::LAST { 1 } else { 0 });
+    /// }
+    /// ```
+    pub fn tiles<
+        const TW: usize,
+        const TH: usize,
+        TM,
+        const TILES: usize,
+        IM,
+        const TSIZE: usize,
+    >(
+        &self,
+    ) -> impl Iterator<
+        Item = (
+            Andex<TM, TILES>,
+            GridTile<'_, M, T, IM, WIDTH, SIZE, TW, TSIZE>,
+        ),
+    > {
+        const {
+            assert!(
+                TW > 0 && WIDTH.is_multiple_of(TW),
+                "tile width must divide grid width"
+            );
+            assert!(
+                TH > 0 && (SIZE / WIDTH).is_multiple_of(TH),
+                "tile height must divide grid height"
+            );
+            assert!(TW * TH == TSIZE, "within-tile andex size must be TW * TH");
+            assert!(
+                (WIDTH / TW) * (SIZE / WIDTH / TH) == TILES,
+                "tile andex size must be the number of tiles"
+            );
+        };
+        let columns = WIDTH / TW;
+        Andex::<TM, TILES>::iter().map(move |tile| {
+            let t = usize::from(tile);
+            let origin = (t / columns) * TH * WIDTH + (t % columns) * TW;
+            (
+                tile,
+                GridTile {
+                    grid: self,
+                    origin,
+                    inner: PhantomData,
+                },
+            )
+        })
+    }
+}
+
+/// View of a tile of a [`BucketGrid`]
+///
+/// Created by [`BucketGrid::tiles`]; indexed by the within-tile andex
+/// `Andex<IM, TSIZE>`, in row-major order.
+pub struct GridTile<
+    'a,
+    M,
+    T,
+    IM,
+    const WIDTH: usize,
+    const SIZE: usize,
+    const TW: usize,
+    const TSIZE: usize,
+> {
+    grid: &'a BucketGrid<M, T, WIDTH, SIZE>,
+    origin: usize,
+    inner: PhantomData<fn() -> IM>,
+}
+
+impl<'a, M, T, IM, const WIDTH: usize, const SIZE: usize, const TW: usize, const TSIZE: usize>
+    GridTile<'a, M, T, IM, WIDTH, SIZE, TW, TSIZE>
+{
+    /// Returns the grid cell that corresponds to the within-tile
+    /// andex
+    pub fn cell(&self, inner: Andex<IM, TSIZE>) -> Andex<M, SIZE> {
+        let i = usize::from(inner);
+        Andex::new_unchecked(self.origin + (i / TW) * WIDTH + i % TW)
+    }
+
+    /// Returns the bucket of the within-tile andex
+    pub fn bucket(&self, inner: Andex<IM, TSIZE>) -> &'a [T] {
+        self.grid.bucket(self.cell(inner))
+    }
+
+    /// Iterates over the within-tile andexes and the corresponding
+    /// buckets
+    pub fn iter(&self) -> impl Iterator<Item = (Andex<IM, TSIZE>, &'a [T])> + '_ {
+        Andex::<IM, TSIZE>::iter().map(move |inner| (inner, self.bucket(inner)))
+    }
+}
+
+impl<M, T, IM, const WIDTH: usize, const SIZE: usize, const TW: usize, const TSIZE: usize>
+    ops::Index<Andex<IM, TSIZE>> for GridTile<'_, M, T, IM, WIDTH, SIZE, TW, TSIZE>
+{
+    type Output = [T];
+    fn index(&self, inner: Andex<IM, TSIZE>) -> &[T] {
+        self.bucket(inner)
+    }
+}
+
+impl<M, T, IM, const WIDTH: usize, const SIZE: usize, const TW: usize, const TSIZE: usize>
+    fmt::Debug for GridTile<'_, M, T, IM, WIDTH, SIZE, TW, TSIZE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "GridTile<{}x{}>({})", TW, TSIZE / TW, self.origin)
+    }
+}
+
+impl<M, T, const WIDTH: usize, const SIZE: usize> Default for BucketGrid<M, T, WIDTH, SIZE> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M, T: fmt::Debug, const WIDTH: usize, const SIZE: usize> fmt::Debug
+    for BucketGrid<M, T, WIDTH, SIZE>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "BucketGrid<{}x{}>({:?})",
+            WIDTH,
+            Self::HEIGHT,
+            self.0.as_ref()
+        )
+    }
+}
+
+/// Wrapper that serializes a [`BucketGrid`] as nested rows
+///
+/// By default, grids are serialized with their shape and a flat list
+/// of buckets in row-major order:
+/// `{"width": 2, "height": 2, "cells": [[], [], [], []]}`. Wrapping the
+/// grid in `NestedGrid` serializes it as a list of rows instead:
+/// `[[[], []], [[], []]]`. In both cases, the shape is validated on
+/// deserialization.
+///
+/// Requires the `serde` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum CellMarker {}
+/// type Cell = Andex<CellMarker, 4>;
+/// type Grid = BucketGrid<CellMarker, u32, 2, { Cell::SIZE }>;
+///
+/// let json = "[[[1], []], [[], [2, 3]]]";
+/// let NestedGrid(grid) = serde_json::from_str::<NestedGrid<Grid>>(json).unwrap();
+/// assert_eq!(grid.bucket(Cell::LAST), &[2, 3]);
+/// assert!(serde_json::from_str::<NestedGrid<Grid>>("[[[1], []]]").is_err());
+/// ```
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct NestedGrid<G>(pub G);
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::*;
+
+    #[derive(Serialize)]
+    struct FlatRef<'a, T> {
+        width: usize,
+        height: usize,
+        cells: &'a [Vec<T>],
+    }
+
+    #[derive(Deserialize)]
+    struct Flat<T> {
+        width: usize,
+        height: usize,
+        cells: Vec<Vec<T>>,
+    }
+
+    impl<M, T, const WIDTH: usize, const SIZE: usize> BucketGrid<M, T, WIDTH, SIZE> {
+        /// Creates the grid from its buckets, checking their number
+        fn from_buckets<E: serde::de::Error>(buckets: Vec<Vec<T>>) -> Result<Self, E> {
+            let len = buckets.len();
+            let array = <[Vec<T>; SIZE]>::try_from(buckets)
+                .map_err(|_| E::invalid_length(len, &"one bucket per cell"))?;
+            Ok(BucketGrid(AndexableArray::from(array)))
+        }
+    }
+
+    impl<M, T: Serialize, const WIDTH: usize, const SIZE: usize> Serialize
+        for BucketGrid<M, T, WIDTH, SIZE>
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            FlatRef {
+                width: WIDTH,
+                height: Self::HEIGHT,
+                cells: self.0.as_ref(),
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de, M, T: Deserialize<'de>, const WIDTH: usize, const SIZE: usize> Deserialize<'de>
+        for BucketGrid<M, T, WIDTH, SIZE>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let flat = Flat::deserialize(deserializer)?;
+            if flat.width != WIDTH || flat.height != Self::HEIGHT {
+                return Err(D::Error::custom(format!(
+                    "grid shape {}x{} doesn't match {}x{}",
+                    flat.width,
+                    flat.height,
+                    WIDTH,
+                    Self::HEIGHT
+                )));
+            }
+            Self::from_buckets(flat.cells)
+        }
+    }
+
+    impl<M, T: Serialize, const WIDTH: usize, const SIZE: usize> Serialize
+        for NestedGrid<BucketGrid<M, T, WIDTH, SIZE>>
+    {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.collect_seq(self.0 .0.as_ref().chunks(WIDTH))
+        }
+    }
+
+    impl<'de, M, T: Deserialize<'de>, const WIDTH: usize, const SIZE: usize> Deserialize<'de>
+        for NestedGrid<BucketGrid<M, T, WIDTH, SIZE>>
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let rows = Vec::<Vec<Vec<T>>>::deserialize(deserializer)?;
+            let height = BucketGrid::<M, T, WIDTH, SIZE>::HEIGHT;
+            if rows.len() != height {
+                return Err(D::Error::invalid_length(
+                    rows.len(),
+                    &"one row per grid row",
+                ));
+            }
+            if let Some(row) = rows.iter().find(|row| row.len() != WIDTH) {
+                return Err(D::Error::invalid_length(
+                    row.len(),
+                    &"one bucket per grid column",
+                ));
+            }
+            BucketGrid::from_buckets(rows.into_iter().flatten().collect()).map(NestedGrid)
+        }
+    }
+}
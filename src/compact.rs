@@ -0,0 +1,220 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! compact module
+//!
+//! Array index stored in a narrower integer type.
+
+use std::cmp;
+use std::convert;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::str;
+
+use crate::andex::*;
+
+/// Integer types that can back a [`CompactAndex`]
+///
+/// Implemented for `u8`, `u16`, `u32` and `usize`.
+pub trait AndexStorage: Copy + Ord + Hash + fmt::Debug {
+    /// The largest value that the type can hold, as an `usize`
+    const MAX: usize;
+    /// Converts from an `usize` that is known to fit
+    fn from_usize(value: usize) -> Self;
+    /// Converts into an `usize`
+    fn to_usize(self) -> usize;
+}
+
+macro_rules! andex_storage_impl {
+    ($($t:ty),+) => {
+        $(
+            impl AndexStorage for $t {
+                const MAX: usize = if <$t>::MAX as u128 > usize::MAX as u128 {
+                    usize::MAX
+                } else {
+                    <$t>::MAX as usize
+                };
+                #[inline]
+                fn from_usize(value: usize) -> Self {
+                    value as $t
+                }
+                #[inline]
+                fn to_usize(self) -> usize {
+                    self as usize
+                }
+            }
+        )+
+    };
+}
+
+andex_storage_impl!(u8, u16, u32, usize);
+
+/// Array index that takes only as much memory as its storage type
+///
+/// [`Andex`] always stores an `usize`. `CompactAndex` holds the same
+/// value in the provided [`AndexStorage`] integer instead, which
+/// saves memory in structures that keep a lot of indexes around. It
+/// converts from and into the corresponding [`Andex`] type, and it
+/// can index the same arrays.
+///
+/// The storage type must be able to hold all values of the index,
+/// which is checked at compile time.
+///
+/// Like [`Andex`], `CompactAndex` is `#[repr(transparent)]`, with the
+/// same layout as its storage type.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum EntityMarker {}
+/// type Entity = Andex<EntityMarker, 200>;
+/// type CompactEntity = CompactAndex<EntityMarker, u8, { Entity::SIZE }>;
+/// type Health = andex_array!(Entity, u32);
+///
+/// let parent = CompactEntity::from(Entity::new::<100>());
+/// assert_eq!(std::mem::size_of_val(&parent), 1);
+///
+/// let mut health = Health::default();
+/// health[parent] = 5;
+/// assert_eq!(health[Entity::new::<100>()], 5);
+/// ```
+///
+/// While the following doesn't compile, as 300 values don't fit in
+/// an `u8`:
+///
+/// ```compile_fail
+/// use andex::*;
+///
+/// enum EntityMarker {}
+/// type Entity = Andex<EntityMarker, 300>;
+/// type CompactEntity = CompactAndex<EntityMarker, u8, { Entity::SIZE }>;
+///
+/// let parent = CompactEntity::from(Entity::FIRST);
+/// ```
+#[repr(transparent)]
+pub struct CompactAndex<M, S, const SIZE: usize>(PhantomData<fn() -> M>, S);
+
+impl<M, S: AndexStorage, const SIZE: usize> CompactAndex<M, S, SIZE> {
+    /// The `SIZE` parameter, which is the size of the array that this
+    /// index indexes.
+    pub const SIZE: usize = SIZE;
+
+    /// Creates the compact version of the provided andex
+    #[inline]
+    pub fn new(andex: Andex<M, SIZE>) -> Self {
+        const {
+            assert!(
+                SIZE - 1 <= S::MAX,
+                "storage type can't hold all values of the index"
+            )
+        };
+        CompactAndex(PhantomData, S::from_usize(usize::from(andex)))
+    }
+
+    /// Iterate all possible values of the index, in order
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Andex::<M, SIZE>::iter().map(CompactAndex::new)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> AndexLike for CompactAndex<M, S, SIZE> {
+    type Andex = Andex<M, SIZE>;
+    #[inline]
+    fn andex(&self) -> Andex<M, SIZE> {
+        Andex::new_unchecked(self.1.to_usize())
+    }
+}
+
+/* Generic implementations */
+
+impl<M, S: AndexStorage, const SIZE: usize> Clone for CompactAndex<M, S, SIZE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> Copy for CompactAndex<M, S, SIZE> {}
+
+impl<M, S: AndexStorage, const SIZE: usize> Hash for CompactAndex<M, S, SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.andex().hash(state);
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> Default for CompactAndex<M, S, SIZE> {
+    fn default() -> Self {
+        CompactAndex::new(Andex::default())
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> PartialEq for CompactAndex<M, S, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.1 == other.1
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> Eq for CompactAndex<M, S, SIZE> {}
+
+impl<M, S: AndexStorage, const SIZE: usize> PartialOrd for CompactAndex<M, S, SIZE> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> Ord for CompactAndex<M, S, SIZE> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.1.cmp(&other.1)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> From<Andex<M, SIZE>> for CompactAndex<M, S, SIZE> {
+    fn from(andex: Andex<M, SIZE>) -> Self {
+        CompactAndex::new(andex)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> From<CompactAndex<M, S, SIZE>> for Andex<M, SIZE> {
+    fn from(compact: CompactAndex<M, S, SIZE>) -> Self {
+        compact.andex()
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> From<CompactAndex<M, S, SIZE>> for usize {
+    fn from(compact: CompactAndex<M, S, SIZE>) -> Self {
+        compact.1.to_usize()
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> convert::TryFrom<usize> for CompactAndex<M, S, SIZE> {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Andex::try_from(value).map(CompactAndex::new)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> fmt::Debug for CompactAndex<M, S, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.andex(), f)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> fmt::Display for CompactAndex<M, S, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.andex(), f)
+    }
+}
+
+impl<M, S: AndexStorage, const SIZE: usize> str::FromStr for CompactAndex<M, S, SIZE> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(CompactAndex::new(s.parse::<Andex<M, SIZE>>()?))
+    }
+}
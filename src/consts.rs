@@ -0,0 +1,63 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_docs)]
+
+//! consts module
+//!
+//! Macro that declares named constants of an [`Andex`](crate::Andex).
+
+/// Declares named constants of an andex type
+///
+/// Each constant is created with [`Andex::new`], so values that are
+/// out of bounds fail to compile. This allows us to give meaningful
+/// names to the indexes that have a special role, instead of
+/// scattering `MyIdx::new::<N>()` calls around.
+///
+/// The constants are declared in the scope where the macro is
+/// invoked, with the optional visibility given before the andex type.
+/// Attributes (including doc comments) are forwarded to each
+/// constant.
+///
+/// [`Andex::new`]: crate::Andex::new
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum SegmentMarker {}
+/// type Segment = Andex<SegmentMarker, 12>;
+///
+/// andex_consts!(pub Segment {
+///     /// The first segment of the snake
+///     HEAD = 0,
+///     TAIL = 11,
+/// });
+///
+/// assert_eq!(HEAD, Segment::FIRST);
+/// assert_eq!(TAIL, Segment::LAST);
+/// ```
+///
+/// While the following doesn't compile, as `12` is out of bounds:
+///
+/// ```compile_fail
+/// use andex::*;
+///
+/// enum SegmentMarker {}
+/// type Segment = Andex<SegmentMarker, 12>;
+///
+/// andex_consts!(Segment { HEAD = 0, TAIL = 12 });
+///
+/// let tail = TAIL;
+/// ```
+#[macro_export]
+macro_rules! andex_consts {
+    ($vis:vis $andex:ty { $($(#[$meta:meta])* $name:ident = $value:expr),* $(,)? }) => {
+        $(
+            $(#[$meta])*
+            $vis const $name: $andex = <$andex>::new::<{ $value }>();
+        )*
+    };
+}
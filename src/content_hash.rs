@@ -0,0 +1,122 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! content_hash module
+//!
+//! Platform-stable hashing of the contents of andexable arrays.
+
+use std::hash::{Hash, Hasher};
+
+use crate::andex::*;
+
+/// 64-bit FNV-1a hasher with platform-independent output
+///
+/// Integers are always fed to the hash in little-endian byte order,
+/// and `usize`/`isize` are widened to 64 bits, so that the same
+/// values produce the same hash on every platform. That's what makes
+/// it suitable to compare state between peers.
+#[derive(Debug, Clone, Copy)]
+pub struct Fnv1aHasher(u64);
+
+impl Fnv1aHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+}
+
+impl Default for Fnv1aHasher {
+    fn default() -> Self {
+        Fnv1aHasher(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for Fnv1aHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u128(&mut self, i: u128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i16(&mut self, i: i16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i64(&mut self, i: i64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_i128(&mut self, i: i128) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write_i64(i as i64);
+    }
+}
+
+impl<A, Item: Hash, const SIZE: usize> AndexableArray<A, Item, SIZE> {
+    /// Returns a platform-stable hash of the items, in index order
+    ///
+    /// Uses [`Fnv1aHasher`]. The hash is stable as long as the `Hash`
+    /// implementation of `Item` only feeds integers, bytes and
+    /// strings to the hasher, which is the case for the primitive
+    /// types and for derived implementations built on them.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 3>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let myu32 = MyU32::from([1, 2, 3]);
+    /// assert_eq!(myu32.content_hash(), MyU32::from([1, 2, 3]).content_hash());
+    /// assert_ne!(myu32.content_hash(), MyU32::from([1, 3, 2]).content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        self.content_hash_with(Fnv1aHasher::default())
+    }
+
+    /// Returns the hash of the items, in index order, computed with
+    /// the provided hasher
+    pub fn content_hash_with<H: Hasher>(&self, mut hasher: H) -> u64 {
+        for item in self {
+            item.hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+}
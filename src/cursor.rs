@@ -0,0 +1,124 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! cursor module
+//!
+//! Round-robin cursor over the values of an [`Andex`].
+
+use std::fmt;
+use std::iter;
+
+use crate::andex::*;
+
+/// Round-robin cursor over the values of an [`Andex`]
+///
+/// The cursor remembers the last andex it returned, and returns the
+/// next one each time it's advanced, wrapping around after
+/// [`Andex::LAST`]. This is useful for fair scheduling over a fixed
+/// set of slots.
+///
+/// The cursor is an infinite [`Iterator`]: `next` always returns
+/// `Some`.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum WorkerIdMarker {}
+/// type WorkerId = Andex<WorkerIdMarker, 3>;
+///
+/// let mut cursor = Cursor::<WorkerId>::default();
+/// assert_eq!(cursor.next(), Some(WorkerId::FIRST));
+/// cursor.advance(1);
+/// assert_eq!(cursor.peek(), WorkerId::LAST);
+/// assert_eq!(cursor.next(), Some(WorkerId::LAST));
+/// assert_eq!(cursor.next(), Some(WorkerId::FIRST));
+/// assert_eq!(cursor.current(), Some(WorkerId::FIRST));
+/// let next = cursor.by_ref().take(4).map(usize::from).collect::<Vec<_>>();
+/// assert_eq!(next, vec![1, 2, 0, 1]);
+/// ```
+pub struct Cursor<A> {
+    last: Option<A>,
+}
+
+impl<M, const SIZE: usize> Cursor<Andex<M, SIZE>> {
+    /// Creates a cursor that starts at [`Andex::FIRST`]
+    pub fn new() -> Self {
+        Cursor { last: None }
+    }
+
+    /// Creates a cursor that starts right after the provided andex
+    pub fn after(last: Andex<M, SIZE>) -> Self {
+        Cursor { last: Some(last) }
+    }
+
+    /// Returns the last andex returned by the cursor, if any
+    pub fn current(&self) -> Option<Andex<M, SIZE>> {
+        self.last
+    }
+
+    /// Returns the andex that the next call to `next` will return,
+    /// without advancing the cursor
+    pub fn peek(&self) -> Andex<M, SIZE> {
+        self.last
+            .and_then(Andex::next)
+            .unwrap_or(Andex::<M, SIZE>::FIRST)
+    }
+
+    /// Advances the cursor by `n` positions without returning them
+    pub fn advance(&mut self, n: usize) {
+        if n > 0 {
+            self.last = Some(match self.last {
+                Some(last) => last.wrapping_add(n),
+                None => Andex::FIRST.wrapping_add(n - 1),
+            });
+        }
+    }
+
+    /// Restarts the cursor at [`Andex::FIRST`]
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+impl<M, const SIZE: usize> Iterator for Cursor<Andex<M, SIZE>> {
+    type Item = Andex<M, SIZE>;
+
+    /// Advances the cursor, returning the next andex
+    fn next(&mut self) -> Option<Self::Item> {
+        let andex = self.peek();
+        self.last = Some(andex);
+        Some(andex)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<M, const SIZE: usize> iter::FusedIterator for Cursor<Andex<M, SIZE>> {}
+
+impl<M, const SIZE: usize> Default for Cursor<Andex<M, SIZE>> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Clone> Clone for Cursor<A> {
+    fn clone(&self) -> Self {
+        Cursor {
+            last: self.last.clone(),
+        }
+    }
+}
+
+impl<A: fmt::Debug> fmt::Debug for Cursor<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Cursor({:?})", self.last)
+    }
+}
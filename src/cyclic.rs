@@ -0,0 +1,211 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! cyclic module
+//!
+//! Array index with ring semantics.
+
+use std::cmp;
+use std::convert;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops;
+use std::str;
+
+use crate::andex::*;
+
+/// Array index that wraps around modulo `SIZE`
+///
+/// Works like [`Andex`], but moving it with `+`, `-`, [`Self::next`]
+/// and [`Self::prev`] always wraps around: the value after
+/// [`Self::LAST`] is [`Self::FIRST`], and vice-versa. This is useful
+/// for circular buffers and round-robin scheduling.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum SlotMarker {}
+/// type Slot = CyclicAndex<SlotMarker, 3>;
+/// type Ring = AndexableArray<Slot, u32, { Slot::SIZE }>;
+///
+/// let mut ring = Ring::default();
+/// let mut head = Slot::FIRST;
+/// for value in 1..=4 {
+///     ring[head] = value;
+///     head += 1;
+/// }
+/// assert_eq!(ring, [4, 2, 3]);
+/// assert_eq!(head, Slot::new::<1>());
+/// assert_eq!(Slot::FIRST.prev(), Slot::LAST);
+/// ```
+#[repr(transparent)]
+pub struct CyclicAndex<M, const SIZE: usize>(Andex<M, SIZE>);
+
+impl<M, const SIZE: usize> CyclicAndex<M, SIZE> {
+    /// The `SIZE` parameter, which is the size of the array that this
+    /// index indexes.
+    pub const SIZE: usize = SIZE;
+
+    /// The first possible value.
+    pub const FIRST: Self = CyclicAndex(Andex::FIRST);
+
+    /// The last possible value.
+    pub const LAST: Self = CyclicAndex(Andex::LAST);
+
+    /// Create a new index instance with the value `N`
+    ///
+    /// The value is checked against the bounds at compile time, as
+    /// in [`Andex::new`].
+    #[inline]
+    pub const fn new<const N: usize>() -> Self {
+        CyclicAndex(Andex::new::<N>())
+    }
+
+    /// Returns the next index, wrapping from [`Self::LAST`] to
+    /// [`Self::FIRST`]
+    #[inline]
+    pub const fn next(self) -> Self {
+        CyclicAndex(self.0.wrapping_add(1))
+    }
+
+    /// Returns the previous index, wrapping from [`Self::FIRST`] to
+    /// [`Self::LAST`]
+    #[inline]
+    pub const fn prev(self) -> Self {
+        CyclicAndex(self.0.wrapping_sub(1))
+    }
+
+    /// Iterate all possible values of the index, in order
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Andex::<M, SIZE>::iter().map(CyclicAndex)
+    }
+}
+
+impl<M, const SIZE: usize> AndexLike for CyclicAndex<M, SIZE> {
+    type Andex = Andex<M, SIZE>;
+    #[inline]
+    fn andex(&self) -> Andex<M, SIZE> {
+        self.0
+    }
+}
+
+/* Ring arithmetic */
+
+impl<M, const SIZE: usize> ops::Add<usize> for CyclicAndex<M, SIZE> {
+    type Output = Self;
+    fn add(self, rhs: usize) -> Self {
+        CyclicAndex(self.0.wrapping_add(rhs))
+    }
+}
+
+impl<M, const SIZE: usize> ops::Sub<usize> for CyclicAndex<M, SIZE> {
+    type Output = Self;
+    fn sub(self, rhs: usize) -> Self {
+        CyclicAndex(self.0.wrapping_sub(rhs))
+    }
+}
+
+impl<M, const SIZE: usize> ops::AddAssign<usize> for CyclicAndex<M, SIZE> {
+    fn add_assign(&mut self, rhs: usize) {
+        *self = *self + rhs;
+    }
+}
+
+impl<M, const SIZE: usize> ops::SubAssign<usize> for CyclicAndex<M, SIZE> {
+    fn sub_assign(&mut self, rhs: usize) {
+        *self = *self - rhs;
+    }
+}
+
+/* Generic implementations */
+
+impl<M, const SIZE: usize> Clone for CyclicAndex<M, SIZE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const SIZE: usize> Copy for CyclicAndex<M, SIZE> {}
+
+impl<M, const SIZE: usize> Hash for CyclicAndex<M, SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<M, const SIZE: usize> Default for CyclicAndex<M, SIZE> {
+    fn default() -> Self {
+        Self::FIRST
+    }
+}
+
+impl<M, const SIZE: usize> PartialEq for CyclicAndex<M, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<M, const SIZE: usize> Eq for CyclicAndex<M, SIZE> {}
+
+impl<M, const SIZE: usize> PartialOrd for CyclicAndex<M, SIZE> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M, const SIZE: usize> Ord for CyclicAndex<M, SIZE> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<M, const SIZE: usize> From<Andex<M, SIZE>> for CyclicAndex<M, SIZE> {
+    fn from(andex: Andex<M, SIZE>) -> Self {
+        CyclicAndex(andex)
+    }
+}
+
+impl<M, const SIZE: usize> From<CyclicAndex<M, SIZE>> for Andex<M, SIZE> {
+    fn from(index: CyclicAndex<M, SIZE>) -> Self {
+        index.0
+    }
+}
+
+impl<M, const SIZE: usize> From<CyclicAndex<M, SIZE>> for usize {
+    fn from(index: CyclicAndex<M, SIZE>) -> Self {
+        usize::from(index.0)
+    }
+}
+
+impl<M, const SIZE: usize> convert::TryFrom<usize> for CyclicAndex<M, SIZE> {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        Andex::try_from(value).map(CyclicAndex)
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Debug for CyclicAndex<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Display for CyclicAndex<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<M, const SIZE: usize> str::FromStr for CyclicAndex<M, SIZE> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse().map(CyclicAndex)
+    }
+}
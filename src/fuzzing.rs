@@ -0,0 +1,44 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! fuzzing module
+//!
+//! Integration with the `arbitrary` crate.
+
+use std::mem;
+
+use arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::andex::*;
+
+/// Generates only valid andex values
+///
+/// Requires the `arbitrary` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+/// use arbitrary::{Arbitrary, Unstructured};
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// let mut u = Unstructured::new(&[0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]);
+/// let idx = MyIdx::arbitrary(&mut u).unwrap();
+/// assert!(idx <= MyIdx::LAST);
+/// ```
+impl<'a, M, const SIZE: usize> Arbitrary<'a> for Andex<M, SIZE> {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Andex::new_unchecked(u.choose_index(SIZE)?))
+    }
+
+    #[inline]
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (0, Some(mem::size_of::<usize>()))
+    }
+}
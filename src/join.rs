@@ -0,0 +1,241 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! join module
+//!
+//! Joining of several storages indexed by the same [`Andex`] type,
+//! ECS-style.
+
+use std::fmt;
+use std::iter;
+use std::slice;
+
+use crate::andex::*;
+
+/* Join trait */
+
+/// Storage that can take part in a [`join`]
+///
+/// A joinable storage produces, for each index of its [`Andex`] type
+/// and in index order, either `Some(item)` if the index is present
+/// in the storage or `None` otherwise. Joining yields only the
+/// indexes that are present in all storages.
+///
+/// This trait is implemented for references to [`AndexableArray`],
+/// which are always present, and for the [`Present`] and [`Mask`]
+/// wrappers, which can be used to get sparse storages.
+pub trait Join {
+    /// The andex type that indexes the storage
+    type Index;
+    /// The item yielded for present indexes
+    type Item;
+    /// The iterator over all indexes of the storage
+    type Iter: Iterator<Item = Option<Self::Item>>;
+    /// Returns the iterator over all indexes of the storage
+    fn join_iter(self) -> Self::Iter;
+}
+
+impl<'a, M, Item, const SIZE: usize> Join for &'a AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    type Index = Andex<M, SIZE>;
+    type Item = &'a Item;
+    type Iter = iter::Map<slice::Iter<'a, Item>, fn(&'a Item) -> Option<&'a Item>>;
+    fn join_iter(self) -> Self::Iter {
+        self.as_ref().iter().map(Some)
+    }
+}
+
+impl<'a, M, Item, const SIZE: usize> Join for &'a mut AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    type Index = Andex<M, SIZE>;
+    type Item = &'a mut Item;
+    type Iter = iter::Map<slice::IterMut<'a, Item>, fn(&'a mut Item) -> Option<&'a mut Item>>;
+    fn join_iter(self) -> Self::Iter {
+        self.as_mut().iter_mut().map(Some)
+    }
+}
+
+/// Sparse storage wrapper for arrays of `Option`
+///
+/// Wrapping a reference to an [`AndexableArray`] of `Option<Item>`
+/// in `Present` makes the indexes that hold `None` absent from the
+/// [`join`], and yields references to the inner items otherwise.
+#[derive(Debug)]
+pub struct Present<S>(pub S);
+
+impl<'a, M, Item, const SIZE: usize> Join
+    for Present<&'a AndexableArray<Andex<M, SIZE>, Option<Item>, SIZE>>
+{
+    type Index = Andex<M, SIZE>;
+    type Item = &'a Item;
+    type Iter = iter::Map<slice::Iter<'a, Option<Item>>, fn(&'a Option<Item>) -> Option<&'a Item>>;
+    fn join_iter(self) -> Self::Iter {
+        self.0.as_ref().iter().map(Option::as_ref)
+    }
+}
+
+impl<'a, M, Item, const SIZE: usize> Join
+    for Present<&'a mut AndexableArray<Andex<M, SIZE>, Option<Item>, SIZE>>
+{
+    type Index = Andex<M, SIZE>;
+    type Item = &'a mut Item;
+    type Iter = iter::Map<
+        slice::IterMut<'a, Option<Item>>,
+        fn(&'a mut Option<Item>) -> Option<&'a mut Item>,
+    >;
+    fn join_iter(self) -> Self::Iter {
+        self.0.as_mut().iter_mut().map(Option::as_mut)
+    }
+}
+
+/// Mask wrapper for arrays of `bool`
+///
+/// Wrapping a reference to an [`AndexableArray`] of `bool` in `Mask`
+/// makes the indexes that hold `false` absent from the [`join`]. The
+/// item yielded for the present indexes is `()`.
+#[derive(Debug)]
+pub struct Mask<S>(pub S);
+
+impl<'a, M, const SIZE: usize> Join for Mask<&'a AndexableArray<Andex<M, SIZE>, bool, SIZE>> {
+    type Index = Andex<M, SIZE>;
+    type Item = ();
+    type Iter = iter::Map<slice::Iter<'a, bool>, fn(&'a bool) -> Option<()>>;
+    fn join_iter(self) -> Self::Iter {
+        self.0.as_ref().iter().map(|&b| b.then_some(()))
+    }
+}
+
+/* Join function and iterator */
+
+/// Tuple of [`Join`] storages that share the same andex type
+///
+/// Implemented for tuples of up to 8 storages.
+pub trait JoinTuple {
+    /// The resulting iterator
+    type Iter: Iterator;
+    /// Returns the iterator over the indexes present in all storages
+    fn join_tuple(self) -> Self::Iter;
+}
+
+/// Iterator returned by [`join`]
+///
+/// Yields a tuple with the index and the item of each storage, for
+/// all indexes that are present in all the storages.
+pub struct JoinIter<M, const SIZE: usize, T> {
+    indexes: AndexIterator<M, SIZE>,
+    iters: T,
+}
+
+impl<M, const SIZE: usize, T: fmt::Debug> fmt::Debug for JoinIter<M, SIZE, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "JoinIter({:?}, {:?})", self.indexes, self.iters)
+    }
+}
+
+macro_rules! join_tuple_impl {
+    ($($s:ident $i:ident $v:ident $n:tt),+) => {
+        impl<M, const SIZE: usize, $($s),+> JoinTuple for ($($s,)+)
+        where
+            $($s: Join<Index = Andex<M, SIZE>>),+
+        {
+            type Iter = JoinIter<M, SIZE, ($($s::Iter,)+)>;
+            fn join_tuple(self) -> Self::Iter {
+                JoinIter {
+                    indexes: Andex::<M, SIZE>::iter(),
+                    iters: ($(self.$n.join_iter(),)+),
+                }
+            }
+        }
+
+        impl<M, const SIZE: usize, $($s,)+ $($i),+> Iterator for JoinIter<M, SIZE, ($($i,)+)>
+        where
+            $($i: Iterator<Item = Option<$s>>),+
+        {
+            type Item = (Andex<M, SIZE>, $($s),+);
+            fn next(&mut self) -> Option<Self::Item> {
+                loop {
+                    let index = self.indexes.next()?;
+                    if let ($(Some($v),)+) = ($(self.iters.$n.next()?,)+) {
+                        return Some((index, $($v),+));
+                    }
+                }
+            }
+        }
+    };
+}
+
+mod impls {
+    use super::*;
+    join_tuple_impl!(A IA a 0);
+    join_tuple_impl!(A IA a 0, B IB b 1);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2, D ID d 3);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2, D ID d 3, E IE e 4);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2, D ID d 3, E IE e 4, F IF f 5);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2, D ID d 3, E IE e 4, F IF f 5, G IG g 6);
+    join_tuple_impl!(A IA a 0, B IB b 1, C IC c 2, D ID d 3, E IE e 4, F IF f 5, G IG g 6, H IH h 7);
+}
+
+/// Joins the provided tuple of storages
+///
+/// Returns an iterator over the indexes that are present in all the
+/// storages, along with the corresponding items. The [`join!`](macro@crate::join) macro
+/// can be used to avoid the tuple.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum EntityIdMarker {}
+/// type EntityId = Andex<EntityIdMarker, 4>;
+///
+/// type Positions = andex_array!(EntityId, i32);
+/// type Velocities = andex_array!(EntityId, Option<i32>);
+/// type Alive = andex_array!(EntityId, bool);
+///
+/// let positions = Positions::from([0, 1, 2, 3]);
+/// let mut velocities = Velocities::from([Some(1), None, Some(1), Some(1)]);
+/// let alive = Alive::from([true, true, false, true]);
+///
+/// for (entity, pos, vel, ()) in join((&positions, Present(&mut velocities), Mask(&alive))) {
+///     *vel += pos;
+///     println!("{} {}", entity, vel);
+/// }
+/// assert_eq!(velocities.as_ref(), &[Some(1), None, Some(1), Some(4)]);
+/// ```
+pub fn join<T: JoinTuple>(storages: T) -> T::Iter {
+    storages.join_tuple()
+}
+
+/// Joins the provided storages
+///
+/// This is a shorthand for calling [`join`] with a tuple.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum EntityIdMarker {}
+/// type EntityId = Andex<EntityIdMarker, 3>;
+///
+/// type Positions = andex_array!(EntityId, i32);
+/// type Velocities = andex_array!(EntityId, i32);
+///
+/// let positions = Positions::from([5, 6, 7]);
+/// let mut velocities = Velocities::from([1, 2, 3]);
+///
+/// for (_entity, pos, vel) in join!(&positions, &mut velocities) {
+///     *vel *= pos;
+/// }
+/// assert_eq!(velocities.as_ref(), &[5, 12, 21]);
+/// ```
+#[macro_export]
+macro_rules! join {
+    ($($storage: expr),+ $(,)?) => {
+        $crate::join(($($storage,)+))
+    };
+}
@@ -65,8 +65,8 @@
 //!   # type MyIdx = Andex<MyIdxMarker, 12>;
 //!   const first : MyIdx = MyIdx::new::<0>();
 //!   ```
-//!   This checks that the value is valid at compile time, as long as you
-//!   use it to create `const` variables.
+//!   This checks that the value is valid at compile time, wherever
+//!   `new` is called.
 //!
 //! - Via `try_from`, which returns `Result<Andex, Error>` that has to be
 //!   checked or explicitly ignored:
@@ -99,6 +99,18 @@
 //!       // ...
 //!   }
 //!   ```
+//!   Native range syntax such as `a..=b` can't be iterated over
+//!   directly, as that requires the unstable `Step` trait. We can
+//!   wrap it in an [`AndexRange`] instead:
+//!   ```rust
+//!   # use andex::*;
+//!   # enum MyIdxMarker {}
+//!   # type MyIdx = Andex<MyIdxMarker, 12>;
+//!   let (a, b) = (MyIdx::new::<2>(), MyIdx::new::<5>());
+//!   for idx in AndexRange::from(a..=b) {
+//!       // ...
+//!   }
+//!   ```
 //!
 //! The assumption that the instances can only hold valid values allows us
 //! to use `get_unsafe` and `get_unsafe_mut` in the indexer
@@ -199,6 +211,30 @@
 //! }
 //! ```
 //!
+//! # Optional features
+//!
+//! andex has no dependencies by default. The following cargo features
+//! enable optional functionality:
+//!
+//! - `arbitrary`: generation of valid [`Andex`] values for fuzzing.
+//! - `bounds-checks`: keeps the bound checks when indexing arrays,
+//!   and validates the internally-created andex values. Useful when
+//!   hunting memory corruption.
+//! - `derive`: `#[derive(Andexable)]`, which turns a fieldless enum
+//!   into the marker and the values of an [`Andex`], and
+//!   `#[derive(AndexNewtype)]`, which implements the traits of the
+//!   andex for newtypes that wrap it.
+//! - `num-traits`: [`Andex`] implementations of the `Bounded`,
+//!   `ToPrimitive` and `FromPrimitive` traits.
+//! - `proptest`: strategy that generates valid [`Andex`] values in
+//!   property tests.
+//! - `quickcheck`: generation and shrinking of valid [`Andex`] values
+//!   in property tests.
+//! - `rand`: random [`Permutation`]s, shuffling of arrays and
+//!   uniform sampling of indexes.
+//! - `serde`: serialization of [`Andex`], [`Patch`], [`BucketGrid`]
+//!   and sparse arrays.
+//!
 //! # Compile-time guarantees
 //!
 //! This is the reason to use Andex instead of a plain array in the
@@ -234,6 +270,35 @@
 //! const myidx : MyIdx = MyIdx::new::<13>();
 //! ```
 //!
+//! - We can't create a non-const [`Andex`] with an out-of-bounds
+//!   value via `new` either.
+//!
+//!   The following code doesn't compile:
+//!
+//! ```compile_fail
+//! use andex::*;
+//! enum MyIdxMarker {}
+//! type MyIdx = Andex<MyIdxMarker, 3>;
+//!
+//! // Error: out-of-bounds value, even outside of const contexts:
+//! let myidx: MyIdx = MyIdx::new::<5>();
+//! assert_eq!(usize::from(myidx), 5_usize);
+//! ```
+//!
+//! - We can't convert an [`Andex`] into an integer type that can't
+//!   hold all of its values.
+//!
+//!   The following code doesn't compile:
+//!
+//! ```compile_fail
+//! use andex::*;
+//! enum MyIdxMarker {}
+//! type MyIdx = Andex<MyIdxMarker, 0x1_0000_0001>;
+//!
+//! // Error: the last value doesn't fit in an u32
+//! let value = u32::from(MyIdx::LAST);
+//! ```
+//!
 //! - We can't index [`AndexableArray`] with a different Andex, even when
 //!   it has the same size. This is what using different markers gets
 //!   us.
@@ -260,3 +325,95 @@
 
 mod andex;
 pub use self::andex::*;
+
+mod join;
+pub use self::join::*;
+
+mod bucket_grid;
+pub use self::bucket_grid::*;
+
+mod tracked;
+pub use self::tracked::*;
+
+mod versioned;
+pub use self::versioned::*;
+
+mod undoable;
+pub use self::undoable::*;
+
+mod patch;
+pub use self::patch::*;
+
+mod binary;
+pub use self::binary::*;
+
+mod content_hash;
+pub use self::content_hash::*;
+
+mod permutation;
+pub use self::permutation::*;
+
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rand")]
+pub use self::random::*;
+
+#[cfg(feature = "derive")]
+pub use andex_derive::{AndexNewtype, Andexable};
+
+#[cfg(feature = "arbitrary")]
+mod fuzzing;
+
+#[cfg(feature = "num-traits")]
+mod numeric;
+
+#[cfg(any(feature = "proptest", feature = "quickcheck"))]
+mod property;
+#[cfg(feature = "proptest")]
+pub use self::property::*;
+
+mod allocator;
+pub use self::allocator::*;
+
+mod cursor;
+pub use self::cursor::*;
+
+mod consts;
+
+mod mirror;
+
+mod names;
+pub use self::names::*;
+
+mod lru;
+pub use self::lru::*;
+
+mod reverse_index;
+pub use self::reverse_index::*;
+
+mod transition;
+pub use self::transition::*;
+
+mod offset;
+pub use self::offset::*;
+
+mod compact;
+pub use self::compact::*;
+
+mod cyclic;
+pub use self::cyclic::*;
+
+mod locks;
+
+#[cfg(feature = "serde")]
+mod serde;
+
+#[cfg(feature = "serde")]
+mod sparse;
+#[cfg(feature = "serde")]
+pub use self::sparse::*;
+
+mod select;
+
+mod window;
+pub use self::window::*;
@@ -0,0 +1,114 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! locks module
+//!
+//! Per-slot locking of andexable arrays of `Mutex` and `RwLock`.
+
+use std::sync::{LockResult, PoisonError};
+use std::sync::{Mutex, MutexGuard};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::andex::*;
+
+/// Combines the results of locking two slots
+///
+/// The result is poisoned if any of the locks is.
+fn both<A, B>(a: LockResult<A>, b: LockResult<B>) -> LockResult<(A, B)> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (a, b) => Err(PoisonError::new((
+            a.unwrap_or_else(PoisonError::into_inner),
+            b.unwrap_or_else(PoisonError::into_inner),
+        ))),
+    }
+}
+
+/// Locking of arrays of `Mutex`
+///
+/// Example:
+///
+/// ```
+/// use std::sync::Mutex;
+/// use andex::*;
+///
+/// enum AccountMarker {}
+/// type Account = Andex<AccountMarker, 3>;
+/// type Balances = andex_array!(Account, Mutex<u32>);
+///
+/// let balances = Balances::from([Mutex::new(10), Mutex::new(0), Mutex::new(0)]);
+/// let (from, to) = (Account::FIRST, Account::LAST);
+/// {
+///     let (mut a, mut b) = balances.lock_two(from, to).unwrap();
+///     *a -= 4;
+///     *b += 4;
+/// }
+/// assert_eq!(*balances.lock(to).unwrap(), 4);
+/// ```
+impl<M, T, const SIZE: usize> AndexableArray<Andex<M, SIZE>, Mutex<T>, SIZE> {
+    /// Locks the mutex of the slot
+    pub fn lock(&self, index: Andex<M, SIZE>) -> LockResult<MutexGuard<'_, T>> {
+        self[index].lock()
+    }
+
+    /// Locks the mutexes of two different slots, returning the guards
+    /// in the order of the arguments
+    ///
+    /// The mutexes are always locked in index order, which prevents
+    /// deadlocks between threads that lock the same pair of slots.
+    ///
+    /// Panics if both indexes are the same, as that would deadlock.
+    pub fn lock_two(
+        &self,
+        a: Andex<M, SIZE>,
+        b: Andex<M, SIZE>,
+    ) -> LockResult<(MutexGuard<'_, T>, MutexGuard<'_, T>)> {
+        assert!(a != b, "can't lock the same slot twice");
+        if a < b {
+            let guard_a = self[a].lock();
+            both(guard_a, self[b].lock())
+        } else {
+            let guard_b = self[b].lock();
+            both(self[a].lock(), guard_b)
+        }
+    }
+}
+
+/// Locking of arrays of `RwLock`
+impl<M, T, const SIZE: usize> AndexableArray<Andex<M, SIZE>, RwLock<T>, SIZE> {
+    /// Locks the slot for reading
+    pub fn read(&self, index: Andex<M, SIZE>) -> LockResult<RwLockReadGuard<'_, T>> {
+        self[index].read()
+    }
+
+    /// Locks the slot for writing
+    pub fn write(&self, index: Andex<M, SIZE>) -> LockResult<RwLockWriteGuard<'_, T>> {
+        self[index].write()
+    }
+
+    /// Locks two different slots for writing, returning the guards in
+    /// the order of the arguments
+    ///
+    /// The locks are always acquired in index order, which prevents
+    /// deadlocks between threads that lock the same pair of slots.
+    ///
+    /// Panics if both indexes are the same, as that would deadlock.
+    pub fn write_two(
+        &self,
+        a: Andex<M, SIZE>,
+        b: Andex<M, SIZE>,
+    ) -> LockResult<(RwLockWriteGuard<'_, T>, RwLockWriteGuard<'_, T>)> {
+        assert!(a != b, "can't lock the same slot twice");
+        if a < b {
+            let guard_a = self[a].write();
+            both(guard_a, self[b].write())
+        } else {
+            let guard_b = self[b].write();
+            both(self[a].write(), guard_b)
+        }
+    }
+}
@@ -0,0 +1,190 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! lru module
+//!
+//! Fixed-capacity least-recently-used cache with andex slots.
+
+use std::array;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops;
+
+use crate::andex::*;
+
+/// Least-recently-used cache whose slots are indexed by an [`Andex`]
+///
+/// The cache has `SIZE` slots. Each key is kept in a slot, which is
+/// identified by an andex that can be used to access the value
+/// afterwards. When all slots are taken, inserting a new key evicts
+/// the least recently used one.
+///
+/// Lookups by key take constant time, while finding the slot to evict
+/// takes time proportional to `SIZE`.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum SlotMarker {}
+/// type Slot = Andex<SlotMarker, 2>;
+/// type Cache = LruCache<Slot, u32, String, { Slot::SIZE }>;
+///
+/// let mut cache = Cache::new();
+/// let slot1 = cache.get_or_insert_with(1, |k| k.to_string());
+/// let slot2 = cache.get_or_insert_with(2, |k| k.to_string());
+/// assert_eq!(cache.get(&1), Some(&"1".to_string()));
+/// // Evicts 2, the least recently used:
+/// let slot3 = cache.get_or_insert_with(3, |k| k.to_string());
+/// assert_eq!(slot3, slot2);
+/// assert_eq!(cache[slot3], "3");
+/// assert_eq!(cache.slot_of(&1), Some(slot1));
+/// assert_eq!(cache.slot_of(&2), None);
+/// ```
+pub struct LruCache<A, K, V, const SIZE: usize> {
+    entries: AndexableArray<A, Option<(K, V)>, SIZE>,
+    used: [u64; SIZE],
+    slots: HashMap<K, A>,
+    tick: u64,
+}
+
+impl<M, K, V, const SIZE: usize> LruCache<Andex<M, SIZE>, K, V, SIZE>
+where
+    K: Hash + Eq + Clone,
+{
+    /// Creates an empty cache
+    pub fn new() -> Self {
+        LruCache {
+            entries: AndexableArray::from(array::from_fn(|_| None)),
+            used: [0; SIZE],
+            slots: HashMap::with_capacity(SIZE),
+            tick: 0,
+        }
+    }
+
+    /// Returns the number of keys in the cache
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns true if the cache has no keys
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns the slot of the key, without marking it as used
+    pub fn slot_of(&self, key: &K) -> Option<Andex<M, SIZE>> {
+        self.slots.get(key).copied()
+    }
+
+    /// Returns the value of the key, marking it as used
+    pub fn get(&mut self, key: &K) -> Option<&V> {
+        let slot = self.slot_of(key)?;
+        self.touch(slot);
+        self.entries[slot].as_ref().map(|(_, value)| value)
+    }
+
+    /// Returns the slot of the key, marking it as used
+    ///
+    /// If the key is not in the cache, the value is created with the
+    /// provided function and stored in a free slot or, if there is
+    /// none, in the slot of the least recently used key, which is
+    /// evicted.
+    pub fn get_or_insert_with<F>(&mut self, key: K, f: F) -> Andex<M, SIZE>
+    where
+        F: FnOnce(&K) -> V,
+    {
+        if let Some(slot) = self.slot_of(&key) {
+            self.touch(slot);
+            return slot;
+        }
+        let slot = Andex::<M, SIZE>::iter()
+            .min_by_key(|&slot| (self.entries[slot].is_some(), self.used[usize::from(slot)]))
+            .expect("cache has at least one slot");
+        if let Some((old, _)) = self.entries[slot].take() {
+            self.slots.remove(&old);
+        }
+        let value = f(&key);
+        self.slots.insert(key.clone(), slot);
+        self.entries[slot] = Some((key, value));
+        self.touch(slot);
+        slot
+    }
+
+    /// Returns the key and value in the slot, if any, without marking
+    /// it as used
+    pub fn get_slot(&self, slot: Andex<M, SIZE>) -> Option<(&K, &V)> {
+        self.entries[slot].as_ref().map(|(key, value)| (key, value))
+    }
+
+    /// Returns the key and mutable value in the slot, if any, without
+    /// marking it as used
+    pub fn get_slot_mut(&mut self, slot: Andex<M, SIZE>) -> Option<(&K, &mut V)> {
+        self.entries[slot]
+            .as_mut()
+            .map(|(key, value)| (&*key, value))
+    }
+
+    /// Iterates over the taken slots, with their keys and values
+    pub fn iter(&self) -> impl Iterator<Item = (Andex<M, SIZE>, &K, &V)> {
+        Andex::<M, SIZE>::iter()
+            .zip(self.entries.iter())
+            .filter_map(|(slot, entry)| entry.as_ref().map(|(key, value)| (slot, key, value)))
+    }
+
+    /// Removes all keys from the cache
+    pub fn clear(&mut self) {
+        for entry in &mut self.entries {
+            *entry = None;
+        }
+        self.slots.clear();
+    }
+
+    fn touch(&mut self, slot: Andex<M, SIZE>) {
+        self.tick += 1;
+        self.used[usize::from(slot)] = self.tick;
+    }
+}
+
+impl<M, K, V, const SIZE: usize> Default for LruCache<Andex<M, SIZE>, K, V, SIZE>
+where
+    K: Hash + Eq + Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A, K: fmt::Debug, V: fmt::Debug, const SIZE: usize> fmt::Debug for LruCache<A, K, V, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "LruCache({:?})", self.entries.as_ref())
+    }
+}
+
+/// Indexing panics if the slot is empty
+impl<M, K, V, const SIZE: usize> ops::Index<Andex<M, SIZE>> for LruCache<Andex<M, SIZE>, K, V, SIZE>
+where
+    K: Hash + Eq + Clone,
+{
+    type Output = V;
+    fn index(&self, slot: Andex<M, SIZE>) -> &V {
+        self.get_slot(slot).expect("cache slot is empty").1
+    }
+}
+
+/// Indexing panics if the slot is empty
+impl<M, K, V, const SIZE: usize> ops::IndexMut<Andex<M, SIZE>>
+    for LruCache<Andex<M, SIZE>, K, V, SIZE>
+where
+    K: Hash + Eq + Clone,
+{
+    fn index_mut(&mut self, slot: Andex<M, SIZE>) -> &mut V {
+        self.get_slot_mut(slot).expect("cache slot is empty").1
+    }
+}
@@ -0,0 +1,121 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_docs)]
+
+//! mirror module
+//!
+//! Macro that defines an enum mirroring the values of an
+//! [`Andex`](crate::Andex).
+
+/// Defines an enum with one variant for each value of an andex
+///
+/// The variants mirror the values of the andex in declaration order,
+/// and the macro checks at compile time that there is exactly one
+/// variant for each value. Converting an andex into the enum then
+/// allows us to `match` on it exhaustively, something that the
+/// compiler can't verify when matching on the raw `usize`.
+///
+/// The enum gets `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`,
+/// `Ord`, `Hash` and `Debug`, conversions from/to the andex and into
+/// `usize`, and [`AndexLike`], which allows it to index
+/// [`AndexableArray`]s.
+///
+/// [`AndexLike`]: crate::AndexLike
+/// [`AndexableArray`]: crate::AndexableArray
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// pub enum DirIdxMarker {}
+/// type DirIdx = Andex<DirIdxMarker, 4>;
+///
+/// andex_enum! {
+///     /// Cardinal directions
+///     pub enum Dir(DirIdx) {
+///         North,
+///         East,
+///         South,
+///         West,
+///     }
+/// }
+///
+/// fn delta(dir: DirIdx) -> (i32, i32) {
+///     match Dir::from(dir) {
+///         Dir::North => (0, -1),
+///         Dir::East => (1, 0),
+///         Dir::South => (0, 1),
+///         Dir::West => (-1, 0),
+///     }
+/// }
+///
+/// assert_eq!(delta(DirIdx::LAST), (-1, 0));
+/// assert_eq!(DirIdx::from(Dir::East), DirIdx::new::<1>());
+/// ```
+///
+/// While the following doesn't compile, as there is no variant for
+/// the last value of the andex:
+///
+/// ```compile_fail
+/// use andex::*;
+///
+/// enum DirIdxMarker {}
+/// type DirIdx = Andex<DirIdxMarker, 4>;
+///
+/// andex_enum! {
+///     pub enum Dir(DirIdx) {
+///         North,
+///         East,
+///         South,
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! andex_enum {
+    ($(#[$meta:meta])* $vis:vis enum $name:ident($andex:ty) {
+        $($(#[$vmeta:meta])* $variant:ident),+ $(,)?
+    }) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        $vis enum $name {
+            $($(#[$vmeta])* $variant),+
+        }
+
+        const _: () = ::std::assert!(
+            [$(stringify!($variant)),+].len() == <$andex>::SIZE,
+            "the enum must have one variant for each value of the andex"
+        );
+
+        impl $crate::AndexLike for $name {
+            type Andex = $andex;
+            #[inline]
+            fn andex(&self) -> $andex {
+                match <$andex>::new_checked(*self as usize) {
+                    ::std::option::Option::Some(andex) => andex,
+                    ::std::option::Option::None => ::std::unreachable!(),
+                }
+            }
+        }
+
+        impl ::std::convert::From<$andex> for $name {
+            fn from(andex: $andex) -> Self {
+                [$($name::$variant),+][usize::from(andex)]
+            }
+        }
+
+        impl ::std::convert::From<$name> for $andex {
+            fn from(value: $name) -> Self {
+                $crate::AndexLike::andex(&value)
+            }
+        }
+
+        impl ::std::convert::From<$name> for usize {
+            fn from(value: $name) -> Self {
+                value as usize
+            }
+        }
+    };
+}
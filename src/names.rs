@@ -0,0 +1,98 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! names module
+//!
+//! Names for the values of an [`Andex`].
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str;
+
+use crate::andex::*;
+
+/// Names for the values of the andexes that use the marker
+///
+/// Implementing this trait for a marker type gives a name to each
+/// value of the corresponding [`Andex`], which can then be used with
+/// [`Andex::name`], [`Andex::from_name`] and the [`Named`] wrapper.
+/// Names are usually the variants of an enum that the andex mirrors.
+pub trait AndexNames<const SIZE: usize> {
+    /// The names of the values, in order
+    const NAMES: [&'static str; SIZE];
+    /// Whether the names are matched ignoring ASCII case when parsing
+    const IGNORE_CASE: bool = false;
+}
+
+impl<M: AndexNames<SIZE>, const SIZE: usize> Andex<M, SIZE> {
+    /// Returns the name of the andex
+    pub fn name(self) -> &'static str {
+        M::NAMES[usize::from(self)]
+    }
+
+    /// Returns the andex with the provided name
+    ///
+    /// The name is matched ignoring ASCII case if
+    /// [`AndexNames::IGNORE_CASE`] is set.
+    pub fn from_name(name: &str) -> Result<Self, Error> {
+        M::NAMES
+            .iter()
+            .position(|n| {
+                if M::IGNORE_CASE {
+                    n.eq_ignore_ascii_case(name)
+                } else {
+                    *n == name
+                }
+            })
+            .map(|i| Andex::try_from(i).expect("names are within bounds"))
+            .ok_or_else(|| Error::UnknownName(name.to_string()))
+    }
+}
+
+/// Wrapper that displays and parses an [`Andex`] by name
+///
+/// `Display` writes the name of the andex. `FromStr` accepts both the
+/// name and the numeric value.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum DirectionMarker {}
+/// type Direction = Andex<DirectionMarker, 4>;
+///
+/// impl AndexNames<4> for DirectionMarker {
+///     const NAMES: [&'static str; 4] = ["north", "east", "south", "west"];
+///     const IGNORE_CASE: bool = true;
+/// }
+///
+/// let Named(direction) = "South".parse::<Named<Direction>>().unwrap();
+/// assert_eq!(direction, Direction::new::<2>());
+/// assert_eq!(Named(direction).to_string(), "south");
+/// let Named(direction) = "3".parse::<Named<Direction>>().unwrap();
+/// assert_eq!(direction.name(), "west");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Named<A>(pub A);
+
+impl<M: AndexNames<SIZE>, const SIZE: usize> fmt::Display for Named<Andex<M, SIZE>> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.name())
+    }
+}
+
+impl<M: AndexNames<SIZE>, const SIZE: usize> str::FromStr for Named<Andex<M, SIZE>> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Andex::from_name(s) {
+            Ok(andex) => Ok(Named(andex)),
+            Err(err) => s.parse().map(Named).map_err(|_| err),
+        }
+    }
+}
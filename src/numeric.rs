@@ -0,0 +1,75 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! numeric module
+//!
+//! Integration with the `num-traits` crate.
+
+use num_traits::{Bounded, FromPrimitive, ToPrimitive};
+
+use crate::andex::*;
+
+/// The bounds are [`Andex::FIRST`] and [`Andex::LAST`]
+///
+/// Requires the `num-traits` feature.
+impl<M, const SIZE: usize> Bounded for Andex<M, SIZE> {
+    fn min_value() -> Self {
+        Self::FIRST
+    }
+
+    fn max_value() -> Self {
+        Self::LAST
+    }
+}
+
+/// Converts the value of the andex
+///
+/// Requires the `num-traits` feature.
+impl<M, const SIZE: usize> ToPrimitive for Andex<M, SIZE> {
+    fn to_i64(&self) -> Option<i64> {
+        usize::from(self).to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        usize::from(self).to_u64()
+    }
+
+    fn to_usize(&self) -> Option<usize> {
+        Some(usize::from(self))
+    }
+}
+
+/// Creates an andex from a number, or `None` if it's out of bounds
+///
+/// Requires the `num-traits` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+/// use num_traits::FromPrimitive;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// assert_eq!(MyIdx::from_i32(11), Some(MyIdx::LAST));
+/// assert_eq!(MyIdx::from_i32(-1), None);
+/// assert_eq!(MyIdx::from_f64(2.0), Some(MyIdx::new::<2>()));
+/// ```
+impl<M, const SIZE: usize> FromPrimitive for Andex<M, SIZE> {
+    fn from_i64(n: i64) -> Option<Self> {
+        usize::from_i64(n).and_then(Self::new_checked)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        usize::from_u64(n).and_then(Self::new_checked)
+    }
+
+    fn from_usize(n: usize) -> Option<Self> {
+        Self::new_checked(n)
+    }
+}
@@ -0,0 +1,187 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! offset module
+//!
+//! Array index with a lower bound other than zero.
+
+use std::cmp;
+use std::convert;
+use std::convert::TryFrom;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str;
+
+use crate::andex::*;
+
+/// Array index with values from `MIN` to `MIN + SIZE - 1`
+///
+/// Works like [`Andex`], but its values start at `MIN` instead of
+/// zero. The arrays it indexes still have `SIZE` items, and the
+/// offset is applied internally when indexing them. This is useful
+/// for 1-based domain values, like calendar months or dice rolls.
+///
+/// Example:
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use andex::*;
+///
+/// enum MonthMarker {}
+/// type Month = OffsetAndex<MonthMarker, 1, 12>;
+/// type MonthDays = AndexableArray<Month, u32, { Month::SIZE }>;
+///
+/// const FEBRUARY: Month = Month::new::<2>();
+/// let mut days = MonthDays::from([31; 12]);
+/// days[FEBRUARY] = 28;
+/// assert_eq!(days[Month::try_from(2).unwrap()], 28);
+/// assert_eq!(usize::from(Month::LAST), 12);
+/// assert!(Month::try_from(0).is_err());
+/// ```
+pub struct OffsetAndex<M, const MIN: usize, const SIZE: usize>(Andex<M, SIZE>);
+
+impl<M, const MIN: usize, const SIZE: usize> OffsetAndex<M, MIN, SIZE> {
+    /// The `SIZE` parameter, which is the size of the array that this
+    /// index indexes.
+    pub const SIZE: usize = SIZE;
+
+    /// The smallest value, `MIN`, as an `usize`.
+    pub const MIN_VALUE: usize = MIN;
+
+    /// The largest value, `MIN + SIZE - 1`, as an `usize`.
+    pub const MAX_VALUE: usize = MIN + SIZE - 1;
+
+    /// The first possible index, with value `MIN`.
+    pub const FIRST: Self = OffsetAndex(Andex::FIRST);
+
+    /// The last possible index, with value `MIN + SIZE - 1`.
+    pub const LAST: Self = OffsetAndex(Andex::LAST);
+
+    /// Create a new index instance with the value `N`
+    ///
+    /// The value is checked against the bounds at compile time; the
+    /// following doesn't compile:
+    ///
+    /// ```compile_fail
+    /// use andex::*;
+    ///
+    /// enum MonthMarker {}
+    /// type Month = OffsetAndex<MonthMarker, 1, 12>;
+    ///
+    /// const INVALID: Month = Month::new::<0>();
+    /// ```
+    #[inline]
+    pub const fn new<const N: usize>() -> Self {
+        const { assert!(N >= MIN && N - MIN < SIZE, "value out of bounds") };
+        OffsetAndex(Andex::new_unchecked(N - MIN))
+    }
+
+    /// Iterate all possible values of the index, in order
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Andex::<M, SIZE>::iter().map(OffsetAndex)
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> AndexLike for OffsetAndex<M, MIN, SIZE> {
+    type Andex = Andex<M, SIZE>;
+    #[inline]
+    fn andex(&self) -> Andex<M, SIZE> {
+        self.0
+    }
+}
+
+/* Generic implementations */
+
+impl<M, const MIN: usize, const SIZE: usize> Clone for OffsetAndex<M, MIN, SIZE> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> Copy for OffsetAndex<M, MIN, SIZE> {}
+
+impl<M, const MIN: usize, const SIZE: usize> Hash for OffsetAndex<M, MIN, SIZE> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> Default for OffsetAndex<M, MIN, SIZE> {
+    fn default() -> Self {
+        Self::FIRST
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> PartialEq for OffsetAndex<M, MIN, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> Eq for OffsetAndex<M, MIN, SIZE> {}
+
+impl<M, const MIN: usize, const SIZE: usize> PartialOrd for OffsetAndex<M, MIN, SIZE> {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> Ord for OffsetAndex<M, MIN, SIZE> {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> From<OffsetAndex<M, MIN, SIZE>> for usize {
+    fn from(index: OffsetAndex<M, MIN, SIZE>) -> Self {
+        usize::from(index.0) + MIN
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> convert::TryFrom<usize> for OffsetAndex<M, MIN, SIZE> {
+    type Error = Error;
+    fn try_from(value: usize) -> Result<Self, Self::Error> {
+        value
+            .checked_sub(MIN)
+            .and_then(|v| Andex::try_from(v).ok())
+            .map(OffsetAndex)
+            .ok_or(Error::OutOfRange {
+                value,
+                min: MIN,
+                max: MIN + SIZE - 1,
+            })
+    }
+}
+
+/// Shows the marker type, the value and the range of valid values,
+/// as in `Marker(3/1..=6)`
+impl<M, const MIN: usize, const SIZE: usize> fmt::Debug for OffsetAndex<M, MIN, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}({}/{}..={})",
+            std::any::type_name::<M>(),
+            usize::from(*self),
+            Self::MIN_VALUE,
+            Self::MAX_VALUE
+        )
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> fmt::Display for OffsetAndex<M, MIN, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", usize::from(*self))
+    }
+}
+
+impl<M, const MIN: usize, const SIZE: usize> str::FromStr for OffsetAndex<M, MIN, SIZE> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(parse_usize(s)?)
+    }
+}
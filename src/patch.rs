@@ -0,0 +1,161 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! patch module
+//!
+//! Typed change records that can be applied to andexable arrays.
+
+use std::fmt;
+
+use crate::andex::*;
+
+/// List of changes to an [`AndexableArray`]
+///
+/// Each change is the index of a slot and the new item for it. A
+/// patch is usually created from two arrays with [`Patch::from_diff`]
+/// and later applied with [`AndexableArray::apply_patch`], which
+/// allows us to transmit only the slots that changed.
+///
+/// When the `serde` feature is enabled, patches can be serialized as
+/// a sequence of `(index, item)` pairs; the indexes are checked
+/// against the bounds on deserialization.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 4>;
+/// type MyU32 = andex_array!(MyIdx, u32);
+///
+/// let old = MyU32::from([1, 2, 3, 4]);
+/// let new = MyU32::from([1, 5, 3, 6]);
+/// let patch = Patch::from_diff(&old, &new);
+/// assert_eq!(patch.len(), 2);
+///
+/// let mut other = old;
+/// other.apply_patch(patch);
+/// assert_eq!(other, new);
+/// ```
+pub struct Patch<A, Item> {
+    changes: Vec<(A, Item)>,
+}
+
+impl<M, Item, const SIZE: usize> Patch<Andex<M, SIZE>, Item> {
+    /// Create an empty patch
+    pub fn new() -> Self {
+        Patch {
+            changes: Vec::new(),
+        }
+    }
+
+    /// Create the patch that turns `old` into `new`
+    pub fn from_diff(
+        old: &AndexableArray<Andex<M, SIZE>, Item, SIZE>,
+        new: &AndexableArray<Andex<M, SIZE>, Item, SIZE>,
+    ) -> Self
+    where
+        Item: PartialEq + Clone,
+    {
+        Patch {
+            changes: old
+                .diff(new)
+                .map(|(index, _, item)| (index, item.clone()))
+                .collect(),
+        }
+    }
+
+    /// Adds a change to the patch
+    pub fn push(&mut self, index: Andex<M, SIZE>, item: Item) {
+        self.changes.push((index, item));
+    }
+
+    /// Returns the number of changes in the patch
+    pub fn len(&self) -> usize {
+        self.changes.len()
+    }
+
+    /// Returns true if the patch has no changes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    /// Iterates over the changes in the patch
+    pub fn iter(&self) -> impl Iterator<Item = (Andex<M, SIZE>, &Item)> {
+        self.changes.iter().map(|(index, item)| (*index, item))
+    }
+}
+
+impl<M, Item, const SIZE: usize> Default for Patch<Andex<M, SIZE>, Item> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Clone, Item: Clone> Clone for Patch<A, Item> {
+    fn clone(&self) -> Self {
+        Patch {
+            changes: self.changes.clone(),
+        }
+    }
+}
+
+impl<A: fmt::Debug, Item: fmt::Debug> fmt::Debug for Patch<A, Item> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Patch({:?})", self.changes)
+    }
+}
+
+impl<M, Item, const SIZE: usize> IntoIterator for Patch<Andex<M, SIZE>, Item> {
+    type Item = (Andex<M, SIZE>, Item);
+    type IntoIter = std::vec::IntoIter<(Andex<M, SIZE>, Item)>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.changes.into_iter()
+    }
+}
+
+impl<M, Item, const SIZE: usize> AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Applies the changes in the provided patch, in order
+    pub fn apply_patch(&mut self, patch: Patch<Andex<M, SIZE>, Item>) {
+        for (index, item) in patch {
+            self[index] = item;
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<M, Item: serde::Serialize, const SIZE: usize> serde::Serialize
+    for Patch<Andex<M, SIZE>, Item>
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(
+            self.changes
+                .iter()
+                .map(|(index, item)| (usize::from(index), item)),
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, M, Item: serde::Deserialize<'de>, const SIZE: usize> serde::Deserialize<'de>
+    for Patch<Andex<M, SIZE>, Item>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error as _;
+        use std::convert::TryFrom;
+        let changes = Vec::<(usize, Item)>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(index, item)| {
+                Andex::try_from(index)
+                    .map(|index| (index, item))
+                    .map_err(D::Error::custom)
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Patch { changes })
+    }
+}
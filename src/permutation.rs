@@ -0,0 +1,158 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! permutation module
+//!
+//! Reorderings of andexable arrays.
+
+use std::fmt;
+use std::ops;
+
+use crate::andex::*;
+
+/// Reordering of the indexes of an andex type
+///
+/// A permutation maps each destination index to the source index of
+/// the item that goes there. Applying it to an [`AndexableArray`]
+/// moves the item at `permutation[i]` to `i`. The same permutation
+/// can be applied to several parallel arrays to reorder them
+/// consistently.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 3>;
+/// type MyU32 = andex_array!(MyIdx, u32);
+/// type MyChar = andex_array!(MyIdx, char);
+/// type MyOrder = andex_array!(MyIdx, MyIdx);
+///
+/// let order = MyOrder::from([
+///     MyIdx::new::<2>(),
+///     MyIdx::new::<0>(),
+///     MyIdx::new::<1>(),
+/// ]);
+/// let permutation = Permutation::new(order).unwrap();
+/// let mut myu32 = MyU32::from([10, 20, 30]);
+/// let mut mychar = MyChar::from(['a', 'b', 'c']);
+/// permutation.apply(&mut myu32);
+/// permutation.apply(&mut mychar);
+/// assert_eq!(myu32.as_ref(), &[30, 10, 20]);
+/// assert_eq!(mychar.as_ref(), &['c', 'a', 'b']);
+/// ```
+pub struct Permutation<M, const SIZE: usize>(AndexableArray<Andex<M, SIZE>, Andex<M, SIZE>, SIZE>);
+
+impl<M, const SIZE: usize> Permutation<M, SIZE> {
+    /// Create a permutation from the provided mapping
+    ///
+    /// Returns `None` if the mapping is not a permutation, i.e. if an
+    /// index appears more than once.
+    pub fn new(mapping: AndexableArray<Andex<M, SIZE>, Andex<M, SIZE>, SIZE>) -> Option<Self> {
+        let mut seen = [false; SIZE];
+        for src in &mapping {
+            let seen = &mut seen[usize::from(src)];
+            if *seen {
+                return None;
+            }
+            *seen = true;
+        }
+        Some(Permutation(mapping))
+    }
+
+    /// Returns the permutation that keeps every item in place
+    pub fn identity() -> Self {
+        Permutation(Andex::<M, SIZE>::iter().collect())
+    }
+
+    /// Returns the permutation that undoes this one
+    pub fn inverse(&self) -> Self {
+        let mut inverse = Self::identity();
+        for (dest, src) in Andex::<M, SIZE>::iter().zip(self.0.iter()) {
+            inverse.0[src] = dest;
+        }
+        inverse
+    }
+
+    /// Iterates over the source indexes, in destination order
+    pub fn iter(&self) -> impl Iterator<Item = Andex<M, SIZE>> + '_ {
+        self.0.iter().copied()
+    }
+
+    /// Reorders the provided array in place
+    pub fn apply<Item>(&self, array: &mut AndexableArray<Andex<M, SIZE>, Item, SIZE>) {
+        let items = array.as_mut();
+        let mut done = [false; SIZE];
+        for start in 0..SIZE {
+            let mut dest = start;
+            while !done[dest] {
+                done[dest] = true;
+                let src = usize::from(self.0.as_ref()[dest]);
+                if src == start {
+                    break;
+                }
+                items.swap(dest, src);
+                dest = src;
+            }
+        }
+    }
+
+    /// Returns a random permutation
+    ///
+    /// Requires the `rand` feature.
+    #[cfg(feature = "rand")]
+    pub fn random<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let mut permutation = Self::identity();
+        permutation.0.shuffle(rng);
+        permutation
+    }
+}
+
+impl<M, const SIZE: usize> Clone for Permutation<M, SIZE> {
+    fn clone(&self) -> Self {
+        Permutation(self.0)
+    }
+}
+
+impl<M, const SIZE: usize> PartialEq for Permutation<M, SIZE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_ref() == other.0.as_ref()
+    }
+}
+
+impl<M, const SIZE: usize> Eq for Permutation<M, SIZE> {}
+
+impl<M, const SIZE: usize> Default for Permutation<M, SIZE> {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Debug for Permutation<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Permutation({:?})", self.0.as_ref())
+    }
+}
+
+impl<M, const SIZE: usize> ops::Index<Andex<M, SIZE>> for Permutation<M, SIZE> {
+    type Output = Andex<M, SIZE>;
+    fn index(&self, index: Andex<M, SIZE>) -> &Self::Output {
+        &self.0[index]
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<A, Item, const SIZE: usize> AndexableArray<A, Item, SIZE> {
+    /// Shuffles the items in place
+    ///
+    /// Requires the `rand` feature.
+    pub fn shuffle<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) {
+        use rand::seq::SliceRandom;
+        self.as_mut().shuffle(rng);
+    }
+}
@@ -0,0 +1,87 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! property module
+//!
+//! Integration with property-based testing crates.
+
+use crate::andex::*;
+
+/* proptest */
+
+/// Strategy that generates all valid andex values
+///
+/// Values shrink towards [`Andex::FIRST`].
+#[cfg(feature = "proptest")]
+pub type AndexStrategy<M, const SIZE: usize> =
+    proptest::strategy::Map<std::ops::Range<usize>, fn(usize) -> Andex<M, SIZE>>;
+
+/// Generates all valid andex values, shrinking towards
+/// [`Andex::FIRST`]
+///
+/// This allows `any::<MyIdx>()` to be used in property tests.
+///
+/// Requires the `proptest` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+/// use proptest::prelude::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// proptest! {
+///     fn pair_is_involutive(idx in any::<MyIdx>()) {
+///         prop_assert_eq!(idx.pair().pair(), idx);
+///     }
+/// }
+/// # pair_is_involutive();
+/// ```
+#[cfg(feature = "proptest")]
+impl<M, const SIZE: usize> proptest::arbitrary::Arbitrary for Andex<M, SIZE> {
+    type Parameters = ();
+    type Strategy = AndexStrategy<M, SIZE>;
+
+    fn arbitrary_with(_args: ()) -> Self::Strategy {
+        proptest::strategy::Strategy::prop_map(0..SIZE, Andex::new_unchecked)
+    }
+}
+
+/* quickcheck */
+
+/// Generates valid andex values, shrinking towards
+/// [`Andex::FIRST`]
+///
+/// Requires the `quickcheck` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+/// use quickcheck::quickcheck;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// fn pair_is_involutive(idx: MyIdx) -> bool {
+///     idx.pair().pair() == idx
+/// }
+/// quickcheck(pair_is_involutive as fn(MyIdx) -> bool);
+/// ```
+#[cfg(feature = "quickcheck")]
+impl<M: 'static, const SIZE: usize> quickcheck::Arbitrary for Andex<M, SIZE> {
+    fn arbitrary(g: &mut quickcheck::Gen) -> Self {
+        Andex::new_unchecked(usize::arbitrary(g) % SIZE)
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // Shrunk values are smaller than the current one, and thus valid
+        Box::new(usize::from(self).shrink().map(Andex::new_unchecked))
+    }
+}
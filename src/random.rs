@@ -0,0 +1,126 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! random module
+//!
+//! Integration with the `rand` crate.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use rand::distr::uniform::{Error, SampleBorrow, SampleUniform, UniformSampler, UniformUsize};
+use rand::distr::{Distribution, StandardUniform};
+use rand::seq::SliceRandom;
+use rand::{Rng, RngExt};
+
+use crate::andex::*;
+
+/// Samples any of the valid andex values, uniformly
+///
+/// This is what `rng.random::<MyIdx>()` uses.
+///
+/// Requires the `rand` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+/// use rand::RngExt;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+///
+/// let mut rng = rand::rng();
+/// let idx = rng.random::<MyIdx>();
+/// let low = rng.random_range(MyIdx::FIRST..=idx);
+/// assert!(low <= idx);
+/// ```
+impl<M, const SIZE: usize> Distribution<Andex<M, SIZE>> for StandardUniform {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Andex<M, SIZE> {
+        Andex::new_unchecked(rng.random_range(0..SIZE))
+    }
+}
+
+impl<M, const SIZE: usize> SampleUniform for Andex<M, SIZE> {
+    type Sampler = UniformAndex<M, SIZE>;
+}
+
+/// Uniform sampler of andex ranges
+///
+/// This is the [`UniformSampler`] used by `rng.random_range` with
+/// ranges of andexes. There's no reason to use it directly.
+///
+/// Requires the `rand` feature.
+pub struct UniformAndex<M, const SIZE: usize>(UniformUsize, PhantomData<fn() -> M>);
+
+impl<M, const SIZE: usize> UniformSampler for UniformAndex<M, SIZE> {
+    type X = Andex<M, SIZE>;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let sampler = UniformUsize::new(usize::from(low.borrow()), usize::from(high.borrow()))?;
+        Ok(UniformAndex(sampler, PhantomData))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Result<Self, Error>
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        let sampler =
+            UniformUsize::new_inclusive(usize::from(low.borrow()), usize::from(high.borrow()))?;
+        Ok(UniformAndex(sampler, PhantomData))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Andex::new_unchecked(self.0.sample(rng))
+    }
+}
+
+impl<M, const SIZE: usize> Clone for UniformAndex<M, SIZE> {
+    fn clone(&self) -> Self {
+        UniformAndex(self.0, PhantomData)
+    }
+}
+
+impl<M, const SIZE: usize> fmt::Debug for UniformAndex<M, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UniformAndex({:?})", self.0)
+    }
+}
+
+impl<M, const SIZE: usize> Andex<M, SIZE> {
+    /// Returns `k` distinct random indexes
+    ///
+    /// Uses a partial Fisher-Yates shuffle, so it takes time
+    /// proportional to `SIZE` to set up and to `k` to sample. If `k`
+    /// is larger than `SIZE`, all indexes are returned, in random
+    /// order.
+    ///
+    /// Requires the `rand` feature.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 12>;
+    ///
+    /// let mut rng = rand::rng();
+    /// let sample = MyIdx::sample(&mut rng, 3);
+    /// assert_eq!(sample.len(), 3);
+    /// ```
+    pub fn sample<R: Rng + ?Sized>(rng: &mut R, k: usize) -> Vec<Self> {
+        let mut all = Self::iter().collect::<Vec<_>>();
+        let (sample, _) = all.partial_shuffle(rng, k.min(SIZE));
+        sample.to_vec()
+    }
+}
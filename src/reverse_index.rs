@@ -0,0 +1,121 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! reverse_index module
+//!
+//! Lookup of the andex that holds a value.
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::andex::*;
+
+/// Map from values to the andex of the slot that holds them
+///
+/// Built from an [`AndexableArray`] or maintained incrementally, it
+/// answers "which slot holds this value" in constant time, instead
+/// of scanning the array.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum SlotMarker {}
+/// type Slot = Andex<SlotMarker, 3>;
+/// type Uuids = andex_array!(Slot, &'static str);
+///
+/// let uuids = Uuids::from(["a1", "b2", "c3"]);
+/// let mut reverse = ReverseIndex::from_array(&uuids);
+/// assert_eq!(reverse.index_of("b2"), Some(Slot::new::<1>()));
+/// reverse.remove("b2");
+/// reverse.insert("d4", Slot::new::<1>());
+/// assert_eq!(reverse.index_of("d4"), Some(Slot::new::<1>()));
+/// ```
+pub struct ReverseIndex<A, T> {
+    map: HashMap<T, A>,
+}
+
+impl<M, T: Hash + Eq, const SIZE: usize> ReverseIndex<Andex<M, SIZE>, T> {
+    /// Creates an empty reverse index
+    pub fn new() -> Self {
+        ReverseIndex {
+            map: HashMap::new(),
+        }
+    }
+
+    /// Creates the reverse index of the provided array
+    ///
+    /// If a value appears in more than one slot, the first one is
+    /// used.
+    pub fn from_array(array: &AndexableArray<Andex<M, SIZE>, T, SIZE>) -> Self
+    where
+        T: Clone,
+    {
+        let mut map = HashMap::with_capacity(SIZE);
+        for (index, value) in Andex::<M, SIZE>::iter().zip(array.iter()) {
+            map.entry(value.clone()).or_insert(index);
+        }
+        ReverseIndex { map }
+    }
+
+    /// Returns the andex of the slot that holds the value
+    pub fn index_of<Q>(&self, value: &Q) -> Option<Andex<M, SIZE>>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(value).copied()
+    }
+
+    /// Records that the value is in the provided slot, returning the
+    /// slot where it was previously
+    pub fn insert(&mut self, value: T, index: Andex<M, SIZE>) -> Option<Andex<M, SIZE>> {
+        self.map.insert(value, index)
+    }
+
+    /// Removes the value, returning the slot where it was
+    pub fn remove<Q>(&mut self, value: &Q) -> Option<Andex<M, SIZE>>
+    where
+        T: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.remove(value)
+    }
+
+    /// Returns the number of values
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns true if there are no values
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+impl<M, T: Hash + Eq, const SIZE: usize> Default for ReverseIndex<Andex<M, SIZE>, T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Clone, T: Clone> Clone for ReverseIndex<A, T> {
+    fn clone(&self) -> Self {
+        ReverseIndex {
+            map: self.map.clone(),
+        }
+    }
+}
+
+impl<A: fmt::Debug, T: fmt::Debug> fmt::Debug for ReverseIndex<A, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ReverseIndex({:?})", self.map)
+    }
+}
@@ -0,0 +1,147 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! select module
+//!
+//! Selection algorithms over andexable arrays.
+
+use std::cmp;
+
+use crate::andex::*;
+
+impl<M, Item, const SIZE: usize> AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Returns the indexes of the `k` largest items, largest first
+    ///
+    /// Equal items are returned in index order. Uses a partial
+    /// selection algorithm, so only the `k` selected indexes get
+    /// sorted. If `k` is larger than `SIZE`, all indexes are returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum PlayerIdMarker {}
+    /// type PlayerId = Andex<PlayerIdMarker, 5>;
+    /// type Scores = andex_array!(PlayerId, u32);
+    ///
+    /// let scores = Scores::from([10, 50, 20, 40, 30]);
+    /// let podium = scores.top_k(3).map(usize::from).collect::<Vec<_>>();
+    /// assert_eq!(podium, vec![1, 3, 4]);
+    /// ```
+    pub fn top_k(&self, k: usize) -> impl Iterator<Item = Andex<M, SIZE>> + '_
+    where
+        Item: Ord,
+    {
+        self.top_k_by_key(k, |item| item)
+    }
+
+    /// Returns the indexes of the `k` items with the largest keys,
+    /// largest first
+    ///
+    /// Works like [`Self::top_k`], comparing the keys returned by `f`.
+    pub fn top_k_by_key<'a, K, F>(
+        &'a self,
+        k: usize,
+        mut f: F,
+    ) -> impl Iterator<Item = Andex<M, SIZE>>
+    where
+        K: Ord,
+        F: FnMut(&'a Item) -> K,
+    {
+        let mut keyed = Andex::<M, SIZE>::iter()
+            .zip(self.iter().map(&mut f))
+            .collect::<Vec<_>>();
+        let compare =
+            |a: &(Andex<M, SIZE>, K), b: &(Andex<M, SIZE>, K)| b.1.cmp(&a.1).then(a.0.cmp(&b.0));
+        let k = cmp::min(k, SIZE);
+        if k > 0 && k < SIZE {
+            keyed.select_nth_unstable_by(k - 1, compare);
+        }
+        keyed.truncate(k);
+        keyed.sort_unstable_by(compare);
+        keyed.into_iter().map(|(index, _)| index)
+    }
+}
+
+impl<A, Item: PartialOrd, const SIZE: usize> AndexableArray<A, Item, SIZE> {
+    /// Returns the median of the items, or `None` if the array is
+    /// empty
+    ///
+    /// For arrays with an even `SIZE`, this is the lower of the two
+    /// middle items. Items that can't be compared, like `NaN`, are
+    /// considered equal to everything.
+    ///
+    /// Uses quickselect on a scratch copy of the array; see
+    /// [`Self::median_mut`] for a version that avoids the copy.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum FrameMarker {}
+    /// type Frame = Andex<FrameMarker, 5>;
+    /// type FrameTimes = andex_array!(Frame, f64);
+    ///
+    /// let times = FrameTimes::from([16.0, 17.5, 16.2, 40.0, 16.9]);
+    /// assert_eq!(times.median(), Some(16.9));
+    /// assert_eq!(times.percentile(100.0), Some(40.0));
+    /// ```
+    pub fn median(&self) -> Option<Item>
+    where
+        Item: Copy,
+    {
+        let mut scratch = *self;
+        scratch.median_mut().copied()
+    }
+
+    /// Returns the item at the provided percentile, or `None` if the
+    /// array is empty or `p` is not in the `0.0..=100.0` range
+    ///
+    /// Uses the nearest-rank definition over the sorted items: `0.0`
+    /// is the minimum, `50.0` the median and `100.0` the maximum.
+    /// Uses quickselect on a scratch copy of the array; see
+    /// [`Self::percentile_mut`] for a version that avoids the copy.
+    pub fn percentile(&self, p: f64) -> Option<Item>
+    where
+        Item: Copy,
+    {
+        let mut scratch = *self;
+        scratch.percentile_mut(p).copied()
+    }
+
+    /// Returns the median of the items, reordering them in the
+    /// process
+    ///
+    /// Works like [`Self::median`], without copying the array.
+    pub fn median_mut(&mut self) -> Option<&Item> {
+        self.select_rank((SIZE.max(1) - 1) / 2)
+    }
+
+    /// Returns the item at the provided percentile, reordering the
+    /// items in the process
+    ///
+    /// Works like [`Self::percentile`], without copying the array.
+    pub fn percentile_mut(&mut self, p: f64) -> Option<&Item> {
+        if !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let rank = (p / 100.0 * (SIZE.max(1) - 1) as f64).round() as usize;
+        self.select_rank(rank)
+    }
+
+    fn select_rank(&mut self, rank: usize) -> Option<&Item> {
+        if SIZE == 0 {
+            return None;
+        }
+        let (_, item, _) = self.as_mut().select_nth_unstable_by(rank, |a, b| {
+            a.partial_cmp(b).unwrap_or(cmp::Ordering::Equal)
+        });
+        Some(item)
+    }
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! serde module
+//!
+//! Integration of [`Andex`] with the `serde` crate.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::andex::*;
+
+/// Serializes the andex as a plain integer
+///
+/// Requires the `serde` feature.
+impl<M, const SIZE: usize> Serialize for Andex<M, SIZE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(usize::from(*self) as u64)
+    }
+}
+
+/// Deserializes the andex from a plain integer, rejecting
+/// out-of-bounds values
+///
+/// Requires the `serde` feature.
+impl<'de, M, const SIZE: usize> Deserialize<'de> for Andex<M, SIZE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = usize::deserialize(deserializer)?;
+        Andex::try_from(value).map_err(D::Error::custom)
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! sparse module
+//!
+//! Serialization of arrays as maps from index to item.
+
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::andex::*;
+
+/// Wrapper that serializes an [`AndexableArray`] as a map from index
+/// to item
+///
+/// On deserialization, slots that are not in the map get their
+/// `Default` value, and indexes that are out of bounds are an error.
+/// This is useful for configuration overrides, which usually specify
+/// only a few of the slots.
+///
+/// Requires the `serde` feature.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 4>;
+/// type MyU32 = andex_array!(MyIdx, u32);
+///
+/// let json = r#"{"1": 5, "3": 7}"#;
+/// let SparseArray(array) = serde_json::from_str::<SparseArray<MyU32>>(json).unwrap();
+/// assert_eq!(array.as_ref(), &[0, 5, 0, 7]);
+/// assert!(serde_json::from_str::<SparseArray<MyU32>>(r#"{"4": 1}"#).is_err());
+/// ```
+#[derive(Debug)]
+pub struct SparseArray<T>(pub T);
+
+impl<M, Item: Serialize, const SIZE: usize> Serialize
+    for SparseArray<AndexableArray<Andex<M, SIZE>, Item, SIZE>>
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.0.iter().enumerate())
+    }
+}
+
+impl<'de, M, Item, const SIZE: usize> Deserialize<'de>
+    for SparseArray<AndexableArray<Andex<M, SIZE>, Item, SIZE>>
+where
+    Item: Deserialize<'de> + Default,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let entries = BTreeMap::<usize, Item>::deserialize(deserializer)?;
+        let mut array = AndexableArray::from(std::array::from_fn(|_| Item::default()));
+        for (index, item) in entries {
+            let index = Andex::<M, SIZE>::try_from(index).map_err(D::Error::custom)?;
+            array[index] = item;
+        }
+        Ok(SparseArray(array))
+    }
+}
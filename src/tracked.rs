@@ -0,0 +1,181 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! tracked module
+//!
+//! Array wrapper that keeps track of the mutated slots.
+
+use std::convert;
+use std::fmt;
+use std::ops;
+
+use crate::andex::*;
+
+/// Array wrapper that records which slots were mutated
+///
+/// Works like [`AndexableArray`], but every mutable access to a slot
+/// marks it as dirty until [`TrackedAndexableArray::clear_dirty`] is
+/// called. This allows us to process only the changed slots, for
+/// instance when syncing state over the network.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+/// type MyU32 = TrackedAndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+///
+/// let mut myu32 = MyU32::default();
+/// myu32[MyIdx::new::<3>()] = 5;
+/// for (idx, value) in myu32.iter_dirty() {
+///     println!("{} changed to {}", idx, value);
+/// }
+/// myu32.clear_dirty();
+/// assert_eq!(myu32.iter_dirty().count(), 0);
+/// ```
+pub struct TrackedAndexableArray<A, Item, const SIZE: usize> {
+    array: AndexableArray<A, Item, SIZE>,
+    dirty: [bool; SIZE],
+}
+
+impl<M, Item, const SIZE: usize> TrackedAndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Returns true if the slot was mutated since the last
+    /// [`Self::clear_dirty`]
+    pub fn is_dirty(&self, index: Andex<M, SIZE>) -> bool {
+        self.dirty[usize::from(index)]
+    }
+
+    /// Marks the slot as dirty without mutating it
+    pub fn mark_dirty(&mut self, index: Andex<M, SIZE>) {
+        self.dirty[usize::from(index)] = true;
+    }
+
+    /// Marks all slots as clean
+    pub fn clear_dirty(&mut self) {
+        self.dirty = [false; SIZE];
+    }
+
+    /// Iterates over the dirty slots, along with their indexes
+    pub fn iter_dirty(&self) -> impl Iterator<Item = (Andex<M, SIZE>, &Item)> {
+        Andex::<M, SIZE>::iter()
+            .zip(self.array.iter())
+            .zip(self.dirty.iter())
+            .filter_map(|(pair, &dirty)| dirty.then_some(pair))
+    }
+
+    /// Replaces the item in the slot and marks it as dirty, returning
+    /// the previous item
+    pub fn replace(&mut self, index: Andex<M, SIZE>, item: Item) -> Item {
+        std::mem::replace(&mut self[index], item)
+    }
+}
+
+impl<A, Item, const SIZE: usize> TrackedAndexableArray<A, Item, SIZE> {
+    /// Returns an iterator over the items
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.array.iter()
+    }
+
+    /// Returns the wrapped array, discarding the dirty flags
+    pub fn into_inner(self) -> AndexableArray<A, Item, SIZE> {
+        self.array
+    }
+}
+
+impl<A, Item: Clone, const SIZE: usize> Clone for TrackedAndexableArray<A, Item, SIZE> {
+    fn clone(&self) -> Self {
+        TrackedAndexableArray {
+            array: self.array.clone(),
+            dirty: self.dirty,
+        }
+    }
+}
+
+impl<A, Item: Default + Copy, const SIZE: usize> Default for TrackedAndexableArray<A, Item, SIZE> {
+    fn default() -> Self {
+        TrackedAndexableArray::from(AndexableArray::default())
+    }
+}
+
+impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for TrackedAndexableArray<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "TrackedAndexableArray<{}>({:?}, dirty: {:?})",
+            std::any::type_name::<Item>(),
+            self.array.as_ref(),
+            self.dirty
+        )
+    }
+}
+
+/// Wraps the array with all slots clean
+impl<A, Item, const SIZE: usize> From<AndexableArray<A, Item, SIZE>>
+    for TrackedAndexableArray<A, Item, SIZE>
+{
+    fn from(array: AndexableArray<A, Item, SIZE>) -> Self {
+        TrackedAndexableArray {
+            array,
+            dirty: [false; SIZE],
+        }
+    }
+}
+
+/// Wraps the array with all slots clean
+impl<A, Item, const SIZE: usize> From<[Item; SIZE]> for TrackedAndexableArray<A, Item, SIZE> {
+    fn from(array: [Item; SIZE]) -> Self {
+        TrackedAndexableArray::from(AndexableArray::from(array))
+    }
+}
+
+impl<A, Item, const SIZE: usize> convert::AsRef<AndexableArray<A, Item, SIZE>>
+    for TrackedAndexableArray<A, Item, SIZE>
+{
+    fn as_ref(&self) -> &AndexableArray<A, Item, SIZE> {
+        &self.array
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<Andex<M, SIZE>>
+    for TrackedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: Andex<M, SIZE>) -> &Self::Output {
+        &self.array[index]
+    }
+}
+
+/// Mutable indexing marks the slot as dirty
+impl<M, Item, const SIZE: usize> ops::IndexMut<Andex<M, SIZE>>
+    for TrackedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    fn index_mut(&mut self, index: Andex<M, SIZE>) -> &mut Item {
+        self.mark_dirty(index);
+        &mut self.array[index]
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<&Andex<M, SIZE>>
+    for TrackedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: &Andex<M, SIZE>) -> &Self::Output {
+        &self.array[index]
+    }
+}
+
+/// Mutable indexing marks the slot as dirty
+impl<M, Item, const SIZE: usize> ops::IndexMut<&Andex<M, SIZE>>
+    for TrackedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    fn index_mut(&mut self, index: &Andex<M, SIZE>) -> &mut Item {
+        self.mark_dirty(*index);
+        &mut self.array[index]
+    }
+}
@@ -0,0 +1,180 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! transition module
+//!
+//! Dense state machine transition tables.
+
+use std::error;
+use std::fmt;
+
+use crate::andex::*;
+
+/// Dense transition table of a state machine
+///
+/// The states and the events are andexes; the table holds the next
+/// state for every (state, event) pair. Tables are created with
+/// [`TransitionTableBuilder`], which checks that all pairs are
+/// defined.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum StateMarker {}
+/// type State = Andex<StateMarker, 2>;
+/// const OFF: State = State::new::<0>();
+/// const ON: State = State::new::<1>();
+///
+/// enum EventMarker {}
+/// type Event = Andex<EventMarker, 2>;
+/// const PUSH: Event = Event::new::<0>();
+/// const RESET: Event = Event::new::<1>();
+///
+/// type Switch = TransitionTable<State, Event, { State::SIZE }, { Event::SIZE }>;
+///
+/// let switch = Switch::builder()
+///     .on(OFF, PUSH, ON)
+///     .on(ON, PUSH, OFF)
+///     .on(OFF, RESET, OFF)
+///     .on(ON, RESET, OFF)
+///     .build()
+///     .unwrap();
+/// assert_eq!(switch.step(OFF, PUSH), ON);
+/// assert!(Switch::builder().on(OFF, PUSH, ON).build().is_err());
+/// ```
+pub struct TransitionTable<S, E, const STATES: usize, const EVENTS: usize>(
+    AndexableArray<S, AndexableArray<E, S, EVENTS>, STATES>,
+);
+
+impl<SM, EM, const STATES: usize, const EVENTS: usize>
+    TransitionTable<Andex<SM, STATES>, Andex<EM, EVENTS>, STATES, EVENTS>
+{
+    /// Returns a builder with no transitions defined
+    pub fn builder() -> TransitionTableBuilder<Andex<SM, STATES>, Andex<EM, EVENTS>, STATES, EVENTS>
+    {
+        TransitionTableBuilder::default()
+    }
+
+    /// Returns the state that follows `state` when `event` happens
+    pub fn step(&self, state: Andex<SM, STATES>, event: Andex<EM, EVENTS>) -> Andex<SM, STATES> {
+        self.0[state][event]
+    }
+
+    /// Returns the transitions from `state`, indexed by event
+    pub fn transitions(
+        &self,
+        state: Andex<SM, STATES>,
+    ) -> &AndexableArray<Andex<EM, EVENTS>, Andex<SM, STATES>, EVENTS> {
+        &self.0[state]
+    }
+}
+
+impl<S: Clone, E, const STATES: usize, const EVENTS: usize> Clone
+    for TransitionTable<S, E, STATES, EVENTS>
+{
+    fn clone(&self) -> Self {
+        TransitionTable(self.0.clone())
+    }
+}
+
+impl<S: fmt::Debug, E, const STATES: usize, const EVENTS: usize> fmt::Debug
+    for TransitionTable<S, E, STATES, EVENTS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|row| row.as_ref()))
+            .finish()
+    }
+}
+
+/// Builder of [`TransitionTable`]s
+pub struct TransitionTableBuilder<S, E, const STATES: usize, const EVENTS: usize>(
+    AndexableArray<S, AndexableArray<E, Option<S>, EVENTS>, STATES>,
+);
+
+impl<SM, EM, const STATES: usize, const EVENTS: usize>
+    TransitionTableBuilder<Andex<SM, STATES>, Andex<EM, EVENTS>, STATES, EVENTS>
+{
+    /// Defines the state that follows `state` when `event` happens,
+    /// replacing the previous definition
+    pub fn on(
+        mut self,
+        state: Andex<SM, STATES>,
+        event: Andex<EM, EVENTS>,
+        next: Andex<SM, STATES>,
+    ) -> Self {
+        self.0[state][event] = Some(next);
+        self
+    }
+
+    /// Builds the table, or returns the first (state, event) pair
+    /// without a transition
+    #[allow(clippy::type_complexity)]
+    pub fn build(
+        self,
+    ) -> Result<
+        TransitionTable<Andex<SM, STATES>, Andex<EM, EVENTS>, STATES, EVENTS>,
+        MissingTransition<Andex<SM, STATES>, Andex<EM, EVENTS>>,
+    > {
+        let mut table =
+            AndexableArray::from([AndexableArray::from([Andex::FIRST; EVENTS]); STATES]);
+        for state in Andex::<SM, STATES>::iter() {
+            for event in Andex::<EM, EVENTS>::iter() {
+                table[state][event] =
+                    self.0[state][event].ok_or(MissingTransition { state, event })?;
+            }
+        }
+        Ok(TransitionTable(table))
+    }
+}
+
+impl<SM, EM, const STATES: usize, const EVENTS: usize> Default
+    for TransitionTableBuilder<Andex<SM, STATES>, Andex<EM, EVENTS>, STATES, EVENTS>
+{
+    fn default() -> Self {
+        TransitionTableBuilder(AndexableArray::from(
+            [AndexableArray::from([None; EVENTS]); STATES],
+        ))
+    }
+}
+
+impl<S: fmt::Debug, E, const STATES: usize, const EVENTS: usize> fmt::Debug
+    for TransitionTableBuilder<S, E, STATES, EVENTS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.0.iter().map(|row| row.as_ref()))
+            .finish()
+    }
+}
+
+/// Error returned by [`TransitionTableBuilder::build`] when a
+/// (state, event) pair has no transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingTransition<S, E> {
+    /// The state without a transition
+    pub state: S,
+    /// The event without a transition from `state`
+    pub event: E,
+}
+
+impl<S: fmt::Debug + fmt::Display, E: fmt::Debug + fmt::Display> error::Error
+    for MissingTransition<S, E>
+{
+}
+
+impl<S: fmt::Display, E: fmt::Display> fmt::Display for MissingTransition<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "no transition from state {} on event {}",
+            self.state, self.event
+        )
+    }
+}
@@ -0,0 +1,251 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! undoable module
+//!
+//! Array wrapper with snapshots and a bounded undo/redo stack.
+
+use std::collections::VecDeque;
+use std::convert;
+use std::fmt;
+use std::ops;
+use std::rc::Rc;
+
+use crate::andex::*;
+
+/// Saved state of an [`UndoableAndexableArray`]
+///
+/// Snapshots share the array with the [`UndoableAndexableArray`] they
+/// were taken from and with each other until one of them is
+/// modified, so taking them is cheap.
+pub struct Snapshot<A, Item, const SIZE: usize>(Rc<AndexableArray<A, Item, SIZE>>);
+
+impl<A, Item, const SIZE: usize> Clone for Snapshot<A, Item, SIZE> {
+    fn clone(&self) -> Self {
+        Snapshot(Rc::clone(&self.0))
+    }
+}
+
+impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for Snapshot<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Snapshot({:?})", self.0)
+    }
+}
+
+impl<A, Item, const SIZE: usize> ops::Deref for Snapshot<A, Item, SIZE> {
+    type Target = AndexableArray<A, Item, SIZE>;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Array wrapper with snapshots and undo/redo support
+///
+/// The array is read by indexing or via `as_ref`, and modified one
+/// item at a time via [`UndoableAndexableArray::edit`], which records
+/// an undo point with the previous value of that item. Up to `limit`
+/// undo points are kept; the oldest ones are dropped when that limit
+/// is exceeded.
+///
+/// Each edit clones only the item being edited. The whole array is
+/// shared copy-on-write with the [`Snapshot`]s, and is only cloned
+/// when it's modified while a snapshot of it is alive.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+/// type MyU32 = UndoableAndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+///
+/// let mut myu32 = MyU32::new(Default::default(), 10);
+/// let snapshot = myu32.snapshot();
+/// *myu32.edit(MyIdx::FIRST) = 5;
+/// *myu32.edit(MyIdx::LAST) = 7;
+/// assert!(myu32.undo());
+/// assert_eq!(myu32[MyIdx::FIRST], 5);
+/// assert_eq!(myu32[MyIdx::LAST], 0);
+/// myu32.restore(&snapshot);
+/// assert_eq!(myu32[MyIdx::FIRST], 0);
+/// ```
+pub struct UndoableAndexableArray<A, Item, const SIZE: usize> {
+    current: Rc<AndexableArray<A, Item, SIZE>>,
+    undo: VecDeque<Change<A, Item, SIZE>>,
+    redo: Vec<Change<A, Item, SIZE>>,
+    limit: usize,
+}
+
+/// Undo/redo point: the state to go back to
+enum Change<A, Item, const SIZE: usize> {
+    /// Value of a single item, with its position in the array
+    Item(usize, Item),
+    /// The whole array, recorded by [`UndoableAndexableArray::restore`]
+    Array(Rc<AndexableArray<A, Item, SIZE>>),
+}
+
+impl<A, Item: Clone, const SIZE: usize> UndoableAndexableArray<A, Item, SIZE> {
+    /// Create a new instance wrapping the provided array, keeping up
+    /// to `limit` undo points
+    pub fn new(array: AndexableArray<A, Item, SIZE>, limit: usize) -> Self {
+        UndoableAndexableArray {
+            current: Rc::new(array),
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            limit,
+        }
+    }
+
+    /// Returns a snapshot of the current state
+    pub fn snapshot(&self) -> Snapshot<A, Item, SIZE> {
+        Snapshot(Rc::clone(&self.current))
+    }
+
+    /// Restores the provided snapshot, recording an undo point
+    pub fn restore(&mut self, snapshot: &Snapshot<A, Item, SIZE>) {
+        let previous = std::mem::replace(&mut self.current, Rc::clone(&snapshot.0));
+        self.record(Change::Array(previous));
+    }
+
+    /// Returns to the state before the last edit
+    ///
+    /// Returns `false` if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        if let Some(change) = self.undo.pop_back() {
+            let change = self.apply(change);
+            self.redo.push(change);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns to the state before the last undo
+    ///
+    /// Returns `false` if there was nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        if let Some(change) = self.redo.pop() {
+            let change = self.apply(change);
+            self.undo.push_back(change);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns the number of undo points currently recorded
+    pub fn undo_len(&self) -> usize {
+        self.undo.len()
+    }
+
+    /// Returns the number of redo points currently recorded
+    pub fn redo_len(&self) -> usize {
+        self.redo.len()
+    }
+
+    /// Drops all undo and redo points
+    pub fn clear_history(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    /// Returns the wrapped array, discarding the history
+    pub fn into_inner(self) -> AndexableArray<A, Item, SIZE> {
+        Rc::try_unwrap(self.current).unwrap_or_else(|rc| (*rc).clone())
+    }
+
+    /// Returns the item at `index` for modification, without
+    /// recording an undo point
+    fn item_mut(&mut self, index: usize) -> &mut Item {
+        &mut Rc::make_mut(&mut self.current).as_mut()[index]
+    }
+
+    /// Applies the change, returning the change that reverts it
+    fn apply(&mut self, change: Change<A, Item, SIZE>) -> Change<A, Item, SIZE> {
+        match change {
+            Change::Item(index, item) => {
+                Change::Item(index, std::mem::replace(self.item_mut(index), item))
+            }
+            Change::Array(array) => Change::Array(std::mem::replace(&mut self.current, array)),
+        }
+    }
+
+    fn record(&mut self, change: Change<A, Item, SIZE>) {
+        self.redo.clear();
+        if self.limit == 0 {
+            return;
+        }
+        if self.undo.len() == self.limit {
+            self.undo.pop_front();
+        }
+        self.undo.push_back(change);
+    }
+}
+
+impl<A, M, Item: Clone, const SIZE: usize> UndoableAndexableArray<A, Item, SIZE>
+where
+    A: AndexLike<Andex = Andex<M, SIZE>>,
+{
+    /// Records an undo point and returns the item at `index` for
+    /// modification
+    ///
+    /// Only the item is cloned to build the undo point. This clears
+    /// the redo stack.
+    pub fn edit<I>(&mut self, index: I) -> &mut Item
+    where
+        I: AndexLike<Andex = Andex<M, SIZE>>,
+    {
+        let index = usize::from(index.andex());
+        if self.limit > 0 {
+            let item = self.current.as_ref().as_ref()[index].clone();
+            self.record(Change::Item(index, item));
+        } else {
+            self.redo.clear();
+        }
+        self.item_mut(index)
+    }
+}
+
+impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for UndoableAndexableArray<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UndoableAndexableArray<{}>({:?}, undo: {}, redo: {})",
+            std::any::type_name::<Item>(),
+            self.current.as_ref().as_ref(),
+            self.undo.len(),
+            self.redo.len()
+        )
+    }
+}
+
+impl<A, Item, const SIZE: usize> convert::AsRef<AndexableArray<A, Item, SIZE>>
+    for UndoableAndexableArray<A, Item, SIZE>
+{
+    fn as_ref(&self) -> &AndexableArray<A, Item, SIZE> {
+        &self.current
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<Andex<M, SIZE>>
+    for UndoableAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: Andex<M, SIZE>) -> &Self::Output {
+        &self.current[index]
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<&Andex<M, SIZE>>
+    for UndoableAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: &Andex<M, SIZE>) -> &Self::Output {
+        &self.current[index]
+    }
+}
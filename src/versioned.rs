@@ -0,0 +1,193 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! versioned module
+//!
+//! Array wrapper that keeps a modification tick per slot.
+
+use std::convert;
+use std::fmt;
+use std::ops;
+
+use crate::andex::*;
+
+/// Array wrapper that records when each slot was last mutated
+///
+/// Works like [`AndexableArray`], but keeps a monotonically
+/// increasing tick that is incremented on every mutable access to a
+/// slot, and stores the tick of the last modification of each slot.
+/// Incremental systems can then save the current tick and later
+/// process only the slots modified since then.
+///
+/// Example:
+///
+/// ```
+/// use andex::*;
+///
+/// enum MyIdxMarker {}
+/// type MyIdx = Andex<MyIdxMarker, 12>;
+/// type MyU32 = VersionedAndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+///
+/// let mut myu32 = MyU32::default();
+/// let synced = myu32.tick();
+/// myu32[MyIdx::new::<3>()] = 5;
+/// for (idx, value) in myu32.iter_modified_since(synced) {
+///     println!("{} changed to {}", idx, value);
+/// }
+/// assert_eq!(myu32.last_modified(MyIdx::new::<3>()), myu32.tick());
+/// ```
+pub struct VersionedAndexableArray<A, Item, const SIZE: usize> {
+    array: AndexableArray<A, Item, SIZE>,
+    ticks: [u64; SIZE],
+    tick: u64,
+}
+
+impl<M, Item, const SIZE: usize> VersionedAndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Returns the tick of the last modification of the slot
+    ///
+    /// Slots that were never modified have tick 0.
+    pub fn last_modified(&self, index: Andex<M, SIZE>) -> u64 {
+        self.ticks[usize::from(index)]
+    }
+
+    /// Marks the slot as modified without mutating it, returning the
+    /// new tick
+    pub fn touch(&mut self, index: Andex<M, SIZE>) -> u64 {
+        self.tick += 1;
+        self.ticks[usize::from(index)] = self.tick;
+        self.tick
+    }
+
+    /// Iterates over the slots modified after the provided tick,
+    /// along with their indexes
+    pub fn iter_modified_since(&self, tick: u64) -> impl Iterator<Item = (Andex<M, SIZE>, &Item)> {
+        Andex::<M, SIZE>::iter()
+            .zip(self.array.iter())
+            .zip(self.ticks.iter())
+            .filter_map(move |(pair, &t)| (t > tick).then_some(pair))
+    }
+
+    /// Replaces the item in the slot and marks it as modified,
+    /// returning the previous item
+    pub fn replace(&mut self, index: Andex<M, SIZE>, item: Item) -> Item {
+        std::mem::replace(&mut self[index], item)
+    }
+}
+
+impl<A, Item, const SIZE: usize> VersionedAndexableArray<A, Item, SIZE> {
+    /// Returns the current tick, which is the tick of the most recent
+    /// modification
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Returns an iterator over the items
+    pub fn iter(&self) -> impl Iterator<Item = &Item> {
+        self.array.iter()
+    }
+
+    /// Returns the wrapped array, discarding the ticks
+    pub fn into_inner(self) -> AndexableArray<A, Item, SIZE> {
+        self.array
+    }
+}
+
+impl<A, Item: Clone, const SIZE: usize> Clone for VersionedAndexableArray<A, Item, SIZE> {
+    fn clone(&self) -> Self {
+        VersionedAndexableArray {
+            array: self.array.clone(),
+            ticks: self.ticks,
+            tick: self.tick,
+        }
+    }
+}
+
+impl<A, Item: Default + Copy, const SIZE: usize> Default
+    for VersionedAndexableArray<A, Item, SIZE>
+{
+    fn default() -> Self {
+        VersionedAndexableArray::from(AndexableArray::default())
+    }
+}
+
+impl<A, Item: fmt::Debug, const SIZE: usize> fmt::Debug for VersionedAndexableArray<A, Item, SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "VersionedAndexableArray<{}>({:?}, ticks: {:?})",
+            std::any::type_name::<Item>(),
+            self.array.as_ref(),
+            self.ticks
+        )
+    }
+}
+
+/// Wraps the array with all slots at tick 0
+impl<A, Item, const SIZE: usize> From<AndexableArray<A, Item, SIZE>>
+    for VersionedAndexableArray<A, Item, SIZE>
+{
+    fn from(array: AndexableArray<A, Item, SIZE>) -> Self {
+        VersionedAndexableArray {
+            array,
+            ticks: [0; SIZE],
+            tick: 0,
+        }
+    }
+}
+
+/// Wraps the array with all slots at tick 0
+impl<A, Item, const SIZE: usize> From<[Item; SIZE]> for VersionedAndexableArray<A, Item, SIZE> {
+    fn from(array: [Item; SIZE]) -> Self {
+        VersionedAndexableArray::from(AndexableArray::from(array))
+    }
+}
+
+impl<A, Item, const SIZE: usize> convert::AsRef<AndexableArray<A, Item, SIZE>>
+    for VersionedAndexableArray<A, Item, SIZE>
+{
+    fn as_ref(&self) -> &AndexableArray<A, Item, SIZE> {
+        &self.array
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<Andex<M, SIZE>>
+    for VersionedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: Andex<M, SIZE>) -> &Self::Output {
+        &self.array[index]
+    }
+}
+
+/// Mutable indexing marks the slot as modified
+impl<M, Item, const SIZE: usize> ops::IndexMut<Andex<M, SIZE>>
+    for VersionedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    fn index_mut(&mut self, index: Andex<M, SIZE>) -> &mut Item {
+        self.touch(index);
+        &mut self.array[index]
+    }
+}
+
+impl<M, Item, const SIZE: usize> ops::Index<&Andex<M, SIZE>>
+    for VersionedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    type Output = Item;
+    fn index(&self, index: &Andex<M, SIZE>) -> &Self::Output {
+        &self.array[index]
+    }
+}
+
+/// Mutable indexing marks the slot as modified
+impl<M, Item, const SIZE: usize> ops::IndexMut<&Andex<M, SIZE>>
+    for VersionedAndexableArray<Andex<M, SIZE>, Item, SIZE>
+{
+    fn index_mut(&mut self, index: &Andex<M, SIZE>) -> &mut Item {
+        self.touch(*index);
+        &mut self.array[index]
+    }
+}
@@ -0,0 +1,113 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![warn(missing_debug_implementations)]
+#![warn(missing_docs)]
+
+//! window module
+//!
+//! Moving-window operations over andexable arrays.
+
+use std::array;
+use std::ops;
+
+use crate::andex::*;
+
+/// Policy for the window positions that fall outside of the array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Edge {
+    /// Use the nearest item of the array
+    Clamp,
+    /// Wrap around to the other end of the array
+    Wrap,
+    /// Use the `Default` value, which is zero for numeric types
+    Zero,
+}
+
+impl<M, Item, const SIZE: usize> AndexableArray<Andex<M, SIZE>, Item, SIZE> {
+    /// Maps each slot to the result of the function applied to the
+    /// window of `window_len` items around it
+    ///
+    /// The window of the slot `i` covers the positions from
+    /// `i - (window_len - 1) / 2` to `i + window_len / 2`; the
+    /// positions outside of the array are filled according to the
+    /// `edge` policy.
+    ///
+    /// Panics if `window_len` is zero.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 4>;
+    /// type MyU32 = andex_array!(MyIdx, u32);
+    ///
+    /// let levels = MyU32::from([4, 8, 0, 4]);
+    /// let peaks = levels.windowed_map(3, Edge::Clamp, |w| *w.iter().max().unwrap());
+    /// assert_eq!(peaks.as_ref(), &[8, 8, 8, 4]);
+    /// ```
+    pub fn windowed_map<R, F>(
+        &self,
+        window_len: usize,
+        edge: Edge,
+        mut f: F,
+    ) -> AndexableArray<Andex<M, SIZE>, R, SIZE>
+    where
+        Item: Clone + Default,
+        F: FnMut(&[Item]) -> R,
+    {
+        assert!(window_len > 0, "window length must be positive");
+        let items = self.as_ref();
+        let before = (window_len - 1) / 2;
+        let mut window = Vec::with_capacity(window_len);
+        AndexableArray::from(array::from_fn(|i| {
+            window.clear();
+            window.extend((0..window_len).map(|j| {
+                let pos = i as isize + j as isize - before as isize;
+                match edge {
+                    _ if (0..SIZE as isize).contains(&pos) => items[pos as usize].clone(),
+                    Edge::Clamp => items[pos.clamp(0, SIZE as isize - 1) as usize].clone(),
+                    Edge::Wrap => items[pos.rem_euclid(SIZE as isize) as usize].clone(),
+                    Edge::Zero => Item::default(),
+                }
+            }));
+            f(&window)
+        }))
+    }
+
+    /// Returns the convolution of the array with the kernel
+    ///
+    /// The kernel is centered on each slot like the window of
+    /// [`Self::windowed_map`], and the positions outside of the array
+    /// are filled according to the `edge` policy.
+    ///
+    /// Panics if the kernel is empty.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use andex::*;
+    ///
+    /// enum MyIdxMarker {}
+    /// type MyIdx = Andex<MyIdxMarker, 5>;
+    /// type MyF32 = andex_array!(MyIdx, f32);
+    ///
+    /// let signal = MyF32::from([0.0, 4.0, 0.0, 0.0, 8.0]);
+    /// let smooth = signal.convolve(&[0.25, 0.5, 0.25], Edge::Zero);
+    /// assert_eq!(smooth.as_ref(), &[1.0, 2.0, 1.0, 2.0, 4.0]);
+    /// ```
+    pub fn convolve(&self, kernel: &[Item], edge: Edge) -> Self
+    where
+        Item: Copy + Default + ops::Add<Output = Item> + ops::Mul<Output = Item>,
+    {
+        self.windowed_map(kernel.len(), edge, |window| {
+            window
+                .iter()
+                .zip(kernel.iter().rev())
+                .fold(Item::default(), |acc, (&x, &k)| acc + x * k)
+        })
+    }
+}
@@ -0,0 +1,24 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for AndexAllocator: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 3>;
+
+#[test]
+fn test_allocator() {
+    let mut allocator = AndexAllocator::<MyIdx>::new();
+    assert_eq!(allocator.remaining(), 3);
+    let ids = std::iter::from_fn(|| allocator.next_id().ok()).collect::<Vec<_>>();
+    assert_eq!(ids, MyIdx::iter().collect::<Vec<_>>());
+    assert_eq!(allocator.remaining(), 0);
+    assert_eq!(allocator.next_id(), Err(Exhausted));
+    assert_eq!(allocator.remaining(), 0);
+    allocator.reset();
+    assert_eq!(allocator.next_id(), Ok(MyIdx::FIRST));
+    assert_eq!(allocator.remaining(), 2);
+}
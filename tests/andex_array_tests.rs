@@ -21,6 +21,7 @@ pub struct NoTraits {}
 type _MyArrayNoTraits = AndexableArray<MyIdx, NoTraits, { MyIdx::SIZE }>;
 
 #[test]
+#[allow(clippy::map_identity)]
 fn test_myarr() -> Result<()> {
     let mut m = MyArray::default();
     m[MyIdx::new::<2>()] = 5;
@@ -54,6 +55,11 @@ fn test_debug() -> Result<()> {
 }
 
 #[test]
+#[allow(
+    clippy::needless_range_loop,
+    clippy::clone_on_copy,
+    clippy::deref_addrof
+)]
 fn test_conversions() -> Result<()> {
     let mut myarray1 = MyArray::from([3; 12]);
     let array1 = myarray1.as_mut();
@@ -77,6 +83,7 @@ fn test_conversions() -> Result<()> {
 }
 
 #[test]
+#[allow(clippy::manual_slice_fill)]
 fn test_iter() -> Result<()> {
     let mut myarray = MyArray2::from([3; 12]);
     for item in &mut myarray {
@@ -97,3 +104,384 @@ fn test_intoiter() -> Result<()> {
     }
     Ok(())
 }
+
+#[test]
+fn test_diff() {
+    let myarray1 = MyArray::from([3; 12]);
+    let mut myarray2 = myarray1;
+    assert_eq!(myarray1.diff(&myarray2).count(), 0);
+    myarray2[MyIdx::new::<4>()] = 7;
+    myarray2[MyIdx::LAST] = 8;
+    let diff = myarray1
+        .diff(&myarray2)
+        .map(|(i, a, b)| (usize::from(i), *a, *b))
+        .collect::<Vec<_>>();
+    assert_eq!(diff, vec![(4, 3, 7), (11, 3, 8)]);
+}
+
+#[test]
+fn test_copy_within() {
+    let mut myarray = (0..12).collect::<MyArray>();
+    myarray.copy_within(MyIdx::new::<2>()..MyIdx::new::<5>(), MyIdx::new::<3>());
+    assert_eq!(myarray.as_ref(), &[0, 1, 2, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
+    myarray.copy_within(MyIdx::LAST..MyIdx::LAST, MyIdx::FIRST);
+    assert_eq!(myarray[MyIdx::FIRST], 0);
+    myarray.copy_within(MyIdx::new::<10>()..=MyIdx::LAST, MyIdx::FIRST);
+    assert_eq!(myarray.as_ref()[..3], [10, 11, 2]);
+    myarray.copy_within(MyIdx::new::<9>().., MyIdx::new::<1>());
+    assert_eq!(myarray.as_ref()[..5], [10, 9, 10, 11, 3]);
+}
+
+#[test]
+#[should_panic]
+fn test_copy_within_overflow() {
+    let mut myarray = MyArray::default();
+    myarray.copy_within(MyIdx::FIRST..MyIdx::new::<2>(), MyIdx::LAST);
+}
+
+#[test]
+fn test_copy_from() -> Result<()> {
+    let mut myarray = MyArray::default();
+    myarray.copy_from(MyIdx::new::<10>(), &[1, 2])?;
+    myarray.copy_from(MyIdx::FIRST, &[])?;
+    assert_eq!(myarray.as_ref(), &[0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2]);
+    let result = myarray.copy_from(MyIdx::LAST, &[3, 4, 5]);
+    assert!(matches!(
+        result,
+        Err(Error::OutOfBounds {
+            value: 13,
+            size: 12
+        })
+    ));
+    assert_eq!(myarray[MyIdx::LAST], 2);
+    Ok(())
+}
+
+#[test]
+fn test_display_fromstr() -> Result<()> {
+    let myarray = (0..12).collect::<MyArray>();
+    let text = myarray.to_string();
+    assert_eq!(text, "0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11");
+    assert_eq!(text.parse::<MyArray>()?, myarray);
+    assert!(matches!(
+        "1, 2".parse::<MyArray>(),
+        Err(ParseArrayError::WrongLength { len: 2, size: 12 })
+    ));
+    assert!(matches!(
+        "".parse::<MyArray>(),
+        Err(ParseArrayError::WrongLength { len: 0, size: 12 })
+    ));
+    let err = "0,1,2,3,4,5,6,7,8,9,10,x".parse::<MyArray>().unwrap_err();
+    assert!(matches!(err, ParseArrayError::Item(_)));
+    println!("{}", err);
+    Ok(())
+}
+
+#[test]
+fn test_content_hash() {
+    let myarray = (0..12).collect::<MyArray>();
+    // Fixed value, the hash must not change across platforms:
+    assert_eq!(myarray.content_hash(), 0xe554_8887_2730_8865);
+    let mut other = myarray;
+    assert_eq!(other.content_hash(), myarray.content_hash());
+    other[MyIdx::LAST] = 0;
+    assert_ne!(other.content_hash(), myarray.content_hash());
+    let hasher = std::collections::hash_map::DefaultHasher::new();
+    assert_eq!(
+        myarray.content_hash_with(hasher.clone()),
+        myarray.content_hash_with(hasher)
+    );
+}
+
+#[test]
+fn test_partition_point() {
+    let myarray = MyArray::from([0, 1, 1, 1, 2, 3, 5, 8, 13, 21, 34, 55]);
+    assert_eq!(
+        myarray.partition_point(|&x| x < 10),
+        Some(MyIdx::new::<8>())
+    );
+    assert_eq!(myarray.partition_point(|_| false), Some(MyIdx::FIRST));
+    assert_eq!(myarray.partition_point(|_| true), None);
+    assert_eq!(myarray.lower_bound(&1), Some(MyIdx::new::<1>()));
+    assert_eq!(myarray.upper_bound(&1), Some(MyIdx::new::<4>()));
+    assert_eq!(myarray.lower_bound(&55), Some(MyIdx::LAST));
+    assert_eq!(myarray.upper_bound(&55), None);
+}
+
+#[test]
+fn test_top_k() {
+    let myarray = MyArray::from([5, 1, 9, 3, 9, 0, 7, 2, 8, 4, 6, 1]);
+    let top = myarray.top_k(4).map(usize::from).collect::<Vec<_>>();
+    assert_eq!(top, vec![2, 4, 8, 6]);
+    assert_eq!(myarray.top_k(0).count(), 0);
+    assert_eq!(myarray.top_k(20).count(), 12);
+    let bottom = myarray
+        .top_k_by_key(3, |&x| std::cmp::Reverse(x))
+        .map(usize::from)
+        .collect::<Vec<_>>();
+    assert_eq!(bottom, vec![5, 1, 11]);
+}
+
+#[test]
+fn test_median_percentile() {
+    let myarray = MyArray::from([5, 1, 9, 3, 9, 0, 7, 2, 8, 4, 6, 1]);
+    assert_eq!(myarray.median(), Some(4));
+    assert_eq!(myarray.percentile(0.0), Some(0));
+    assert_eq!(myarray.percentile(100.0), Some(9));
+    assert_eq!(myarray.percentile(90.0), Some(9));
+    assert_eq!(myarray.percentile(101.0), None);
+    assert_eq!(myarray.percentile(f64::NAN), None);
+    let mut scratch = myarray;
+    assert_eq!(scratch.median_mut(), Some(&4));
+    assert_eq!(scratch.percentile_mut(25.0), Some(&2));
+    enum EmptyMarker {}
+    type Empty = Andex<EmptyMarker, 0>;
+    assert_eq!(
+        andex::AndexableArray::<Empty, u32, 0>::from([]).median(),
+        None
+    );
+}
+
+#[test]
+fn test_iter_runs() {
+    let myarray = MyArray::from([1, 1, 1, 2, 3, 3, 1, 1, 1, 1, 1, 4]);
+    let runs = myarray
+        .iter_runs()
+        .map(|(start, len, item)| (usize::from(start), len, *item))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        runs,
+        vec![(0, 3, 1), (3, 1, 2), (4, 2, 3), (6, 5, 1), (11, 1, 4)]
+    );
+    assert_eq!(MyArray::from([7; 12]).iter_runs().count(), 1);
+}
+
+#[derive(Clone, Copy)]
+pub struct MyNewIdx(MyIdx);
+
+impl AndexLike for MyNewIdx {
+    type Andex = MyIdx;
+    fn andex(&self) -> MyIdx {
+        self.0
+    }
+}
+
+#[test]
+fn test_andexlike() {
+    let mut myarray = AndexableArray::<MyNewIdx, u32, { MyIdx::SIZE }>::default();
+    let newidx = MyNewIdx(MyIdx::new::<3>());
+    myarray[newidx] = 7;
+    myarray[&MyNewIdx(MyIdx::LAST)] = 8;
+    assert_eq!(myarray[MyIdx::new::<3>()], 7);
+    assert_eq!(myarray[&newidx], 7);
+    let mut plain = MyArray::default();
+    plain[newidx] = 9;
+    assert_eq!(plain[MyIdx::new::<3>()], 9);
+}
+
+#[test]
+fn test_plain_array_at() {
+    let mut array = [0_u32; 12];
+    for (num, i) in MyIdx::iter().enumerate() {
+        *array.at_mut(i) = num as u32;
+    }
+    assert_eq!(*array.at(MyIdx::new::<5>()), 5);
+    assert_eq!(*array.at(MyIdx::LAST), 11);
+}
+
+#[test]
+fn test_checked_view() {
+    let data = (0..15).collect::<Vec<u32>>();
+    let view = AndexChecked::<_, MyIdx>::try_view(&data).unwrap();
+    assert_eq!(view[MyIdx::new::<5>()], 5);
+    assert_eq!(view[&MyIdx::LAST], 11);
+    assert_eq!(view.as_slice().len(), MyIdx::SIZE);
+    assert!(AndexChecked::<_, MyIdx>::try_view(&data[..11]).is_none());
+}
+
+#[test]
+fn test_try_from_slice() {
+    let mut data = (0..12).collect::<Vec<u32>>();
+    let view = MyArray::try_from_slice(&data).unwrap();
+    assert_eq!(view[MyIdx::LAST], 11);
+    let view = MyArray::try_from_slice_mut(&mut data).unwrap();
+    view[MyIdx::FIRST] = 7;
+    assert_eq!(data[0], 7);
+    assert!(MyArray::try_from_slice(&data[1..]).is_none());
+    let mut array = [0_u32; 12];
+    MyArray::from_array_mut(&mut array)[MyIdx::LAST] = 3;
+    assert_eq!(MyArray::from_array_ref(&array)[MyIdx::LAST], 3);
+}
+
+#[test]
+#[should_panic]
+fn test_collect_too_short() {
+    let _ = (0..11).collect::<MyArray>();
+}
+
+#[test]
+#[should_panic]
+fn test_collect_too_long() {
+    let _ = (0..13).collect::<MyArray>();
+}
+
+#[test]
+fn test_collect_drops_on_panic() {
+    let counter = std::rc::Rc::new(());
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        (0..5)
+            .map(|_| counter.clone())
+            .collect::<AndexableArray<MyIdx, _, { MyIdx::SIZE }>>()
+    }));
+    assert!(result.is_err());
+    assert_eq!(std::rc::Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn test_predicates() {
+    let myarray = (0..12).collect::<MyArray>();
+    assert!(myarray.any(|i, &item| usize::from(i) == 3 && item == 3));
+    assert!(!myarray.any(|_, &item| item > 11));
+    assert!(myarray.all(|i, &item| usize::from(i) as u32 == item));
+    assert!(!myarray.all(|_, &item| item > 0));
+    assert_eq!(myarray.count_matching(|_, &item| item % 2 == 0), 6);
+    assert_eq!(
+        myarray.find_map_index(|i, &item| (item > 4).then_some(i)),
+        Some(MyIdx::new::<5>())
+    );
+    assert_eq!(
+        myarray.find_map_index(|i, &item| (item > 20).then_some(i)),
+        None
+    );
+}
+
+pub struct MyChunkIdxInner;
+type MyChunkIdx = Andex<MyChunkIdxInner, 3>;
+
+#[test]
+fn test_as_chunks() {
+    let mut myarray = (0..12).collect::<MyArray>();
+    let chunks: &AndexableArray<MyChunkIdx, [u32; 4], 3> = myarray.as_chunks();
+    assert_eq!(chunks[MyChunkIdx::new::<1>()], [4, 5, 6, 7]);
+    let chunks = myarray.as_chunks_mut::<4, MyChunkIdx, 3>();
+    for chunk in chunks {
+        chunk.reverse();
+    }
+    assert_eq!(myarray.as_ref(), &[3, 2, 1, 0, 7, 6, 5, 4, 11, 10, 9, 8]);
+}
+
+#[test]
+fn test_uninit() {
+    let mut myarray =
+        AndexableArray::<MyIdx, std::mem::MaybeUninit<u32>, { MyIdx::SIZE }>::uninit();
+    for (num, i) in MyIdx::iter().enumerate() {
+        *myarray.write(i, 0) += num as u32;
+    }
+    let myarray = unsafe { myarray.assume_init() };
+    assert_eq!(myarray, (0..12).collect::<MyArray>());
+}
+
+#[test]
+fn test_andex_arr() {
+    const MYARRAY: MyArray = andex_arr![MyArray; 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    assert_eq!(MYARRAY, (0..12).collect::<MyArray>());
+}
+
+#[test]
+fn test_sum_product() {
+    let arrays = (1..4)
+        .map(|n| MyIdx::iter().map(|_| n).collect::<MyArray>())
+        .collect::<Vec<_>>();
+    let sum = arrays.iter().sum::<MyArray>();
+    assert!(sum.iter().all(|&item| item == 6));
+    let product = arrays.into_iter().product::<MyArray>();
+    assert!(product.iter().all(|&item| item == 6));
+    assert_eq!(
+        std::iter::empty::<MyArray>().sum::<MyArray>(),
+        MyArray::default()
+    );
+    let one = std::iter::empty::<&MyArray>().product::<MyArray>();
+    assert!(one.iter().all(|&item| item == 1));
+}
+
+pub struct MyHeadIdxInner;
+type MyHeadIdx = Andex<MyHeadIdxInner, 4>;
+
+pub struct MyTailIdxInner;
+type MyTailIdx = Andex<MyTailIdxInner, 8>;
+
+#[test]
+fn test_split() {
+    let mut myarray = (0..12).collect::<MyArray>();
+    let (head, tail) = myarray.split_mut::<4, MyHeadIdx, MyTailIdx, 8>();
+    head[MyHeadIdx::LAST] = 30;
+    tail[MyTailIdx::FIRST] = 40;
+    let (head, tail): (
+        AndexableArray<MyHeadIdx, u32, 4>,
+        AndexableArray<MyTailIdx, u32, 8>,
+    ) = myarray.split();
+    assert_eq!(head.as_ref(), &[0, 1, 2, 30]);
+    assert_eq!(tail.as_ref(), &[40, 5, 6, 7, 8, 9, 10, 11]);
+}
+
+#[test]
+fn test_windowed_map_convolve() {
+    let myarray = (0..12).collect::<MyArray>();
+    let sums = myarray.windowed_map(2, Edge::Zero, |w| w.iter().sum::<u32>());
+    assert_eq!(sums.as_ref(), &[1, 3, 5, 7, 9, 11, 13, 15, 17, 19, 21, 11]);
+    let firsts = myarray.windowed_map(3, Edge::Wrap, |w| w[0]);
+    assert_eq!(firsts.as_ref(), &[11, 0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    let lasts = myarray.windowed_map(3, Edge::Clamp, |w| w[2]);
+    assert_eq!(lasts.as_ref(), &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 11]);
+    // The kernel is flipped, as in a proper convolution:
+    let shifted = myarray.convolve(&[1, 0, 0], Edge::Zero);
+    assert_eq!(shifted, myarray.windowed_map(3, Edge::Zero, |w| w[2]));
+    assert_eq!(myarray.convolve(&[1], Edge::Wrap), myarray);
+}
+
+pub struct MyTiledIdxInner;
+type MyTiledIdx = Andex<MyTiledIdxInner, 36>;
+
+#[test]
+fn test_tile() {
+    let myarray = (0..12).collect::<MyArray>();
+    let tiled: AndexableArray<MyTiledIdx, u32, 36> = myarray.tile::<3, _, 36>();
+    for i in MyTiledIdx::iter() {
+        let (rep, orig): (MyChunkIdx, MyIdx) = i.untile();
+        assert_eq!(usize::from(i), usize::from(rep) * 12 + usize::from(orig));
+        assert_eq!(tiled[i], myarray[orig]);
+    }
+}
+
+#[test]
+fn test_fill_prefix_swap_with_slice() -> Result<()> {
+    let mut myarray = MyArray::default();
+    assert_eq!(myarray.fill_prefix(std::iter::empty()), Some(MyIdx::FIRST));
+    assert_eq!(myarray.fill_prefix(vec![1, 2, 3]), Some(MyIdx::new::<3>()));
+    let mut iter = 0..20;
+    assert_eq!(myarray.fill_prefix(&mut iter), None);
+    assert_eq!(iter.next(), Some(12));
+    let mut other = vec![7; 12];
+    myarray.swap_with_slice(&mut other)?;
+    assert_eq!(other, (0..12).collect::<Vec<_>>());
+    assert!(myarray.iter().all(|&item| item == 7));
+    assert!(matches!(
+        myarray.swap_with_slice(&mut other[1..]),
+        Err(Error::WrongLength { len: 11, size: 12 })
+    ));
+    Ok(())
+}
+
+#[test]
+fn test_eq_arrays_slices() {
+    let myarray = (0..12).collect::<MyArray>();
+    let plain = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11];
+    assert_eq!(myarray, plain);
+    assert_eq!(plain, myarray);
+    assert_eq!(myarray, &plain[..]);
+    assert_eq!(&plain[..], myarray);
+    assert_eq!(myarray, plain[..]);
+    assert_eq!(plain[..], myarray);
+    assert_ne!(myarray, &plain[1..]);
+    assert_ne!(myarray, [0; 12]);
+}
@@ -14,6 +14,7 @@ enum Marker {}
 type C = Andex<Marker, 3>;
 
 #[test]
+#[allow(clippy::clone_on_copy)]
 fn test_basic() -> Result<()> {
     for i in C::iter() {
         assert!(usize::from(i) < 3_usize);
@@ -33,13 +34,6 @@ fn test_basic() -> Result<()> {
     Ok(())
 }
 
-#[test]
-#[should_panic]
-fn test_oob1() {
-    let u: C = C::new::<5>();
-    assert_eq!(usize::from(u), 5_usize);
-}
-
 // // This doesn't compile, which is correct:
 // #[test]
 // #[should_panic]
@@ -50,7 +44,7 @@ fn test_oob1() {
 
 #[test]
 fn test_try_from() {
-    let result = C::try_from(5);
+    let result = C::try_from(5_usize);
     assert!(result.is_err());
     if let Err(ref err) = result {
         println!("{}, {:?}", err, err);
@@ -111,3 +105,574 @@ fn test_sync() {
     fn assert_sync<T: Sync>() {}
     assert_sync::<C>();
 }
+
+/* Indexable */
+
+fn indexable_values<I: Indexable>() -> Vec<usize> {
+    I::iter().map(Indexable::as_usize).collect()
+}
+
+fn indexable_parse<I: Indexable>(value: usize) -> Option<I> {
+    I::try_from(value).ok()
+}
+
+#[test]
+fn test_indexable() {
+    assert_eq!(indexable_values::<C>(), vec![0, 1, 2]);
+    assert_eq!(<C as Indexable>::SIZE, 3);
+    assert_eq!(indexable_parse::<C>(2), Some(C::LAST));
+    assert_eq!(indexable_parse::<C>(3), None);
+}
+
+#[test]
+fn test_marker_auto_traits() {
+    fn is_send_sync_static<T: Send + Sync + 'static>() {}
+    struct PtrMarker(#[allow(dead_code)] *const u8);
+    struct RefMarker<'a>(#[allow(dead_code)] &'a mut u8);
+    is_send_sync_static::<Andex<PtrMarker, 3>>();
+    is_send_sync_static::<AndexableArray<Andex<PtrMarker, 3>, u32, 3>>();
+    fn covariant<'a>(idx: Andex<RefMarker<'static>, 3>) -> Andex<RefMarker<'a>, 3> {
+        idx
+    }
+    assert_eq!(covariant(Andex::FIRST), Andex::FIRST);
+}
+
+enum PanicMarker {}
+type PanicIdx = Andex<PanicMarker, 3>;
+impl AndexPolicy for PanicMarker {}
+
+enum ClampMarker {}
+type ClampIdx = Andex<ClampMarker, 3>;
+impl AndexPolicy for ClampMarker {
+    const OUT_OF_BOUNDS: OutOfBoundsPolicy = OutOfBoundsPolicy::Clamp;
+}
+
+enum WrapMarker {}
+type WrapIdx = Andex<WrapMarker, 3>;
+impl AndexPolicy for WrapMarker {
+    const OUT_OF_BOUNDS: OutOfBoundsPolicy = OutOfBoundsPolicy::Wrap;
+}
+
+#[test]
+fn test_policy() {
+    assert_eq!(PanicIdx::FIRST + 2, PanicIdx::LAST);
+    assert_eq!(PanicIdx::LAST - 2, PanicIdx::FIRST);
+    assert_eq!(PanicIdx::from_usize(1), PanicIdx::new::<1>());
+    assert_eq!(ClampIdx::FIRST + usize::MAX, ClampIdx::LAST);
+    assert_eq!(ClampIdx::LAST - 5, ClampIdx::FIRST);
+    assert_eq!(ClampIdx::from_usize(7), ClampIdx::LAST);
+    assert_eq!(WrapIdx::LAST + 1, WrapIdx::FIRST);
+    assert_eq!(WrapIdx::FIRST - 1, WrapIdx::LAST);
+    assert_eq!(WrapIdx::new::<1>() - 3, WrapIdx::new::<1>());
+    assert_eq!(
+        WrapIdx::new::<1>() + usize::MAX,
+        WrapIdx::new::<1>() + (usize::MAX % 3)
+    );
+    assert_eq!(WrapIdx::from_usize(7), WrapIdx::new::<1>());
+}
+
+#[test]
+#[should_panic]
+fn test_policy_panic_add() {
+    let _ = PanicIdx::LAST + 1;
+}
+
+#[test]
+#[should_panic]
+fn test_policy_panic_sub() {
+    let _ = PanicIdx::FIRST - 1;
+}
+
+#[test]
+fn test_checked_arith() {
+    assert_eq!(C::FIRST.checked_add(2), Some(C::LAST));
+    assert_eq!(C::FIRST.checked_add(3), None);
+    assert_eq!(C::LAST.checked_add(usize::MAX), None);
+    assert_eq!(C::LAST.checked_sub(1), Some(C::new::<1>()));
+    assert_eq!(C::LAST.checked_sub(3), None);
+    const NEXT: Option<C> = C::FIRST.checked_add(1);
+    assert_eq!(NEXT, Some(C::new::<1>()));
+}
+
+#[test]
+fn test_saturating_arith() {
+    assert_eq!(C::FIRST.saturating_add(1), C::new::<1>());
+    assert_eq!(C::FIRST.saturating_add(5), C::LAST);
+    assert_eq!(C::LAST.saturating_add(usize::MAX), C::LAST);
+    assert_eq!(C::LAST.saturating_sub(1), C::new::<1>());
+    assert_eq!(C::LAST.saturating_sub(5), C::FIRST);
+}
+
+#[test]
+fn test_wrapping_arith() {
+    assert_eq!(C::LAST.wrapping_add(1), C::FIRST);
+    assert_eq!(C::new::<1>().wrapping_add(5), C::FIRST);
+    assert_eq!(
+        C::LAST.wrapping_add(usize::MAX),
+        C::new::<2>().wrapping_add(usize::MAX % 3)
+    );
+    assert_eq!(C::FIRST.wrapping_sub(1), C::LAST);
+    assert_eq!(C::FIRST.wrapping_sub(3), C::FIRST);
+    assert_eq!(C::new::<1>().wrapping_sub(usize::MAX), C::new::<1>());
+}
+
+#[test]
+fn test_distance() {
+    assert_eq!(C::FIRST.distance(C::LAST), 2);
+    assert_eq!(C::LAST.distance(C::FIRST), 2);
+    assert_eq!(C::LAST.abs_diff(C::new::<1>()), 1);
+    assert_eq!(C::LAST.distance(C::LAST), 0);
+}
+
+#[test]
+fn test_offset() {
+    assert_eq!(C::FIRST.offset(2), Some(C::LAST));
+    assert_eq!(C::LAST.offset(-2), Some(C::FIRST));
+    assert_eq!(C::LAST.offset(0), Some(C::LAST));
+    assert_eq!(C::LAST.offset(1), None);
+    assert_eq!(C::FIRST.offset(-1), None);
+    assert_eq!(C::FIRST.offset(isize::MIN), None);
+    assert_eq!(C::LAST.offset(isize::MAX), None);
+}
+
+#[test]
+fn test_is_first_last() {
+    assert!(C::FIRST.is_first());
+    assert!(!C::FIRST.is_last());
+    assert!(C::LAST.is_last());
+    assert!(!C::new::<1>().is_first());
+    assert!(!C::new::<1>().is_last());
+    const { assert!(Andex::<Marker, 1>::FIRST.is_last()) };
+}
+
+#[test]
+fn test_iter_nth() {
+    enum BigMarker {}
+    type Big = Andex<BigMarker, 100000>;
+    let mut it = Big::iter();
+    assert_eq!(it.len(), 100000);
+    assert_eq!(it.nth(1000).map(usize::from), Some(1000));
+    assert_eq!(it.len(), 98999);
+    assert_eq!(it.next().map(usize::from), Some(1001));
+    assert_eq!(
+        Big::iter().skip(99999).map(usize::from).collect::<Vec<_>>(),
+        vec![99999]
+    );
+    assert_eq!(it.nth(usize::MAX), None);
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    assert_eq!(C::iter().next_back(), Some(C::LAST));
+}
+
+#[test]
+fn test_iter_range() {
+    enum Marker6 {}
+    type I6 = Andex<Marker6, 6>;
+    let a = I6::new::<1>();
+    let b = I6::new::<4>();
+    let values = |it: AndexIterator<Marker6, 6>| it.map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values(I6::iter_range(a, b)), vec![1, 2, 3]);
+    assert_eq!(values(I6::iter_range_inclusive(a, b)), vec![1, 2, 3, 4]);
+    assert_eq!(values(I6::iter_range(b, a)), vec![]);
+    assert_eq!(values(I6::iter_range(a, a)), vec![]);
+    assert_eq!(values(I6::iter_range_inclusive(a, a)), vec![1]);
+    assert_eq!(values(I6::iter_range_inclusive(b, a)), vec![]);
+    assert_eq!(
+        values(I6::iter_range_inclusive(I6::FIRST, I6::LAST)).len(),
+        6
+    );
+    assert_eq!(I6::iter_range(a, b).len(), 3);
+}
+
+#[test]
+fn test_range() {
+    enum Marker6 {}
+    type I6 = Andex<Marker6, 6>;
+    type R6 = AndexRange<Marker6, 6>;
+    struct Holder {
+        range: R6,
+    }
+    let a = I6::new::<1>();
+    let b = I6::new::<4>();
+    let holder = Holder {
+        range: R6::from(a..b),
+    };
+    let values = |r: R6| r.into_iter().map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values(holder.range), vec![1, 2, 3]);
+    assert_eq!(values(R6::from(a..=b)), vec![1, 2, 3, 4]);
+    assert_eq!(values(R6::from_bounds(b..)), vec![4, 5]);
+    assert_eq!(values(R6::from_bounds(..a)), vec![0]);
+    assert_eq!(values(R6::from_bounds(..)), values(R6::FULL));
+    assert_eq!(R6::FULL.len(), 6);
+    let range = holder.range;
+    assert_eq!(range.len(), 3);
+    assert_eq!(range.first(), Some(a));
+    assert_eq!(range.last(), Some(I6::new::<3>()));
+    assert!(range.contains(a));
+    assert!(!range.contains(b));
+    assert_eq!((&range).into_iter().count(), 3);
+    let empty = R6::from(b..a);
+    assert!(empty.is_empty());
+    assert_eq!(empty.len(), 0);
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty, R6::default());
+    assert_eq!(R6::from(b..b), R6::default());
+    assert_eq!(format!("{:?}", range), "AndexRange(1..4)");
+}
+
+#[test]
+fn test_iter_from() {
+    let values = |it: AndexIterator<Marker, 3>| it.map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values(C::iter_from(C::FIRST)), vec![0, 1, 2]);
+    assert_eq!(values(C::iter_from(C::new::<1>())), vec![1, 2]);
+    assert_eq!(values(C::iter_from(C::LAST)), vec![2]);
+}
+
+#[test]
+fn test_iter_rev() {
+    let values = C::iter_rev().map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values, vec![2, 1, 0]);
+    let mut it = C::iter();
+    assert_eq!(it.next_back(), Some(C::LAST));
+    assert_eq!(it.next(), Some(C::FIRST));
+    assert_eq!(it.next_back(), Some(C::new::<1>()));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+    let mut it = C::iter();
+    assert_eq!(it.nth_back(1), Some(C::new::<1>()));
+    assert_eq!(it.nth_back(5), None);
+    assert_eq!(it.len(), 0);
+}
+
+#[test]
+fn test_try_from_widths() -> Result<()> {
+    assert_eq!(C::try_from(2_u8)?, C::LAST);
+    assert_eq!(C::try_from(1_u16)?, C::new::<1>());
+    assert_eq!(C::try_from(0_u32)?, C::FIRST);
+    assert_eq!(C::try_from(2_u64)?, C::LAST);
+    assert!(matches!(
+        C::try_from(3_u8),
+        Err(Error::OutOfBounds { value: 3, size: 3 })
+    ));
+    assert!(C::try_from(u16::MAX).is_err());
+    assert!(C::try_from(u32::MAX).is_err());
+    assert!(C::try_from(u64::MAX).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_try_from_signed() -> Result<()> {
+    assert_eq!(C::try_from(2_i64)?, C::LAST);
+    assert_eq!(C::try_from(1_isize)?, C::new::<1>());
+    assert!(matches!(
+        C::try_from(-1_i32),
+        Err(Error::NotRepresentable { value: -1, size: 3 })
+    ));
+    assert!(matches!(
+        C::try_from(i64::MIN),
+        Err(Error::NotRepresentable { value, size: 3 }) if value == i128::from(i64::MIN)
+    ));
+    assert!(matches!(
+        C::try_from(isize::MIN),
+        Err(Error::NotRepresentable { value, size: 3 }) if value == isize::MIN as i128
+    ));
+    assert_eq!(
+        C::try_from(-5_i8).unwrap_err().to_string(),
+        "value -5 is out-of-bounds for index with size 3, valid values are 0 to 2"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_try_from_wide() {
+    // The value in the error is always the one provided, even when it
+    // doesn't fit in an usize:
+    match C::try_from(u64::MAX) {
+        Err(Error::OutOfBounds { value, size: 3 }) => assert_eq!(value as u64, u64::MAX),
+        Err(Error::NotRepresentable { value, size: 3 }) => {
+            assert_eq!(value, i128::from(u64::MAX))
+        }
+        other => panic!("unexpected result {:?}", other),
+    }
+    assert_eq!(
+        C::try_from(u64::MAX).unwrap_err().to_string(),
+        format!(
+            "value {} is out-of-bounds for index with size 3, valid values are 0 to 2",
+            u64::MAX
+        )
+    );
+}
+
+#[test]
+fn test_into_widths() {
+    assert_eq!(u32::from(C::LAST), 2_u32);
+    assert_eq!(u64::from(C::new::<1>()), 1_u64);
+    assert_eq!(i64::from(C::FIRST), 0_i64);
+    enum BigMarker {}
+    type Big = Andex<BigMarker, 0x1_0000_0000>;
+    assert_eq!(u64::from(Big::LAST), u64::from(u32::MAX));
+}
+
+#[test]
+fn test_new_checked() {
+    const SECOND: Option<C> = C::new_checked(1);
+    assert_eq!(SECOND, Some(C::new::<1>()));
+    assert_eq!(C::new_checked(2), Some(C::LAST));
+    assert_eq!(C::new_checked(3), None);
+    assert_eq!(C::new_checked(usize::MAX), None);
+}
+
+#[test]
+fn test_as_usize() {
+    const SECOND: usize = C::new::<1>().as_usize();
+    assert_eq!(SECOND, 1);
+    const { assert!(C::LAST.as_usize() == 2) };
+    assert_eq!(Indexable::as_usize(C::LAST), C::LAST.as_usize());
+}
+
+#[test]
+fn test_const_next_prev() {
+    const NEXTS: [Option<C>; 3] = {
+        let mut table = [None; 3];
+        let mut i = Some(C::FIRST);
+        while let Some(idx) = i {
+            table[idx.as_usize()] = idx.next();
+            i = idx.next();
+        }
+        table
+    };
+    assert_eq!(NEXTS, [Some(C::new::<1>()), Some(C::LAST), None]);
+    const PREV: Option<C> = C::LAST.prev();
+    assert_eq!(PREV, Some(C::new::<1>()));
+    assert_eq!(C::FIRST.prev(), None);
+    const PAIR: C = C::FIRST.pair();
+    assert_eq!(PAIR, C::LAST);
+    const TAIL: AndexIterator<Marker, 3> = C::iter_from(C::LAST);
+    assert_eq!(TAIL.collect::<Vec<_>>(), vec![C::LAST]);
+}
+
+#[test]
+fn test_layout() {
+    use std::mem::{align_of, size_of};
+    assert_eq!(size_of::<C>(), size_of::<usize>());
+    assert_eq!(align_of::<C>(), align_of::<usize>());
+    #[repr(C)]
+    struct Header {
+        magic: u32,
+        index: C,
+    }
+    #[repr(C)]
+    struct RawHeader {
+        magic: u32,
+        index: usize,
+    }
+    assert_eq!(size_of::<Header>(), size_of::<RawHeader>());
+    let header = Header {
+        magic: 7,
+        index: C::LAST,
+    };
+    let raw: RawHeader = unsafe { std::mem::transmute(header) };
+    assert_eq!((raw.magic, raw.index), (7, 2));
+}
+
+#[test]
+fn test_count_min_max() {
+    assert_eq!(C::COUNT, 3);
+    assert_eq!(C::COUNT, C::iter().count());
+    assert_eq!(C::MIN, C::FIRST);
+    assert_eq!(C::MAX, C::LAST);
+    assert_eq!(C::iter().min(), Some(C::MIN));
+    assert_eq!(C::iter().max(), Some(C::MAX));
+}
+
+#[test]
+fn test_all() {
+    const ALL: [C; 3] = C::ALL;
+    assert_eq!(ALL.to_vec(), C::iter().collect::<Vec<_>>());
+    assert_eq!(ALL[C::SIZE - 1], C::LAST);
+}
+
+#[test]
+fn test_pair_around() {
+    enum Marker6 {}
+    type I6 = Andex<Marker6, 6>;
+    let pivot = I6::new::<2>();
+    let pairs = I6::iter()
+        .map(|i| i.checked_pair_around(pivot).map(usize::from))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        pairs,
+        vec![Some(4), Some(3), Some(2), Some(1), Some(0), None]
+    );
+    assert_eq!(I6::FIRST.pair_around(pivot), I6::new::<4>());
+    for i in C::iter() {
+        assert_eq!(i.pair_around(C::new::<1>()), i.pair());
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_pair_around_oob() {
+    let _ = C::FIRST.pair_around(C::LAST);
+}
+
+#[test]
+fn test_decompose() {
+    enum CellMarker {}
+    type Cell = Andex<CellMarker, 6>;
+    enum RowMarker {}
+    type Row = Andex<RowMarker, 2>;
+    enum ColMarker {}
+    type Col = Andex<ColMarker, 3>;
+    let coords = Cell::iter()
+        .map(|c| {
+            let (row, col): (Row, Col) = c.decompose();
+            (usize::from(row), usize::from(col))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(coords, vec![(0, 0), (0, 1), (0, 2), (1, 0), (1, 1), (1, 2)]);
+    const LAST: (Row, Col) = Cell::LAST.decompose();
+    assert_eq!(LAST, (Row::LAST, Col::LAST));
+}
+
+#[test]
+fn test_compose() {
+    enum CellMarker {}
+    type Cell = Andex<CellMarker, 6>;
+    enum RowMarker {}
+    type Row = Andex<RowMarker, 2>;
+    enum ColMarker {}
+    type Col = Andex<ColMarker, 3>;
+    for cell in Cell::iter() {
+        let (row, col): (Row, Col) = cell.decompose();
+        assert_eq!(Cell::compose(row, col), cell);
+    }
+    const LAST: Cell = Cell::compose(Row::LAST, Col::LAST);
+    assert_eq!(LAST, Cell::LAST);
+}
+
+#[test]
+fn test_debug() {
+    assert_eq!(format!("{:?}", C::LAST), "andex_tests::Marker(2/3)");
+    assert_eq!(
+        format!("{:?}", Some(C::FIRST)),
+        "Some(andex_tests::Marker(0/3))"
+    );
+}
+
+#[test]
+fn test_display_alternate() {
+    assert_eq!(format!("{}", C::LAST), "2");
+    assert_eq!(format!("{:#}", C::LAST), "2 of 3");
+    assert_eq!(C::FIRST.to_string(), "0");
+}
+
+#[test]
+fn test_from_str_radix() -> Result<()> {
+    assert_eq!("2".parse::<C>()?, C::LAST);
+    assert_eq!("0x2".parse::<C>()?, C::LAST);
+    assert_eq!("0X1".parse::<C>()?, C::new::<1>());
+    assert_eq!("0o2".parse::<C>()?, C::LAST);
+    assert_eq!("0b10".parse::<C>()?, C::LAST);
+    assert!(matches!("0b2".parse::<C>(), Err(Error::ParseIntError(_))));
+    assert!(matches!("".parse::<C>(), Err(Error::ParseIntError(_))));
+    assert!(matches!(
+        "0x10".parse::<C>(),
+        Err(Error::OutOfBounds { value: 16, size: 3 })
+    ));
+    assert_eq!(
+        "-1".parse::<C>().unwrap_err().to_string(),
+        "invalid andex value: invalid digit found in string"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_cast() {
+    enum OtherMarker {}
+    type Other = Andex<OtherMarker, 3>;
+    let casted = C::iter()
+        .map(|i| i.cast::<OtherMarker>())
+        .collect::<Vec<_>>();
+    assert_eq!(casted, Other::iter().collect::<Vec<_>>());
+    assert_eq!(Other::LAST.cast::<Marker>(), C::LAST);
+}
+
+#[test]
+fn test_widen() {
+    enum WideMarker {}
+    type Wide = Andex<WideMarker, 5>;
+    let widened = C::iter().map(|i| i.widen()).collect::<Vec<Wide>>();
+    assert_eq!(widened, Wide::iter().take(3).collect::<Vec<_>>());
+    const SAME: C = C::LAST.widen();
+    assert_eq!(SAME, C::LAST);
+}
+
+#[test]
+fn test_narrow() -> Result<()> {
+    enum WideMarker {}
+    type Wide = Andex<WideMarker, 5>;
+    let narrowed: C = Wide::new::<2>().narrow()?;
+    assert_eq!(narrowed, C::LAST);
+    assert!(matches!(
+        Wide::LAST.narrow::<Marker, 3>(),
+        Err(Error::OutOfBounds { value: 4, size: 3 })
+    ));
+    for i in C::iter() {
+        assert_eq!(i.widen::<WideMarker, 5>().narrow::<Marker, 3>()?, i);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_cmp_usize() {
+    let limit: usize = 2;
+    assert!(C::FIRST == 0);
+    assert!(C::LAST != 0);
+    assert!(C::FIRST < limit);
+    assert!(C::LAST <= limit);
+    assert!(C::LAST > 1);
+    assert_eq!(C::iter().filter(|&i| i < 2).count(), 2);
+}
+
+#[test]
+fn test_policy_assign() {
+    let mut i = PanicIdx::FIRST;
+    i += 2;
+    assert_eq!(i, PanicIdx::LAST);
+    i -= 1;
+    assert_eq!(i, PanicIdx::new::<1>());
+    let mut w = WrapIdx::LAST;
+    w += 1;
+    assert_eq!(w, WrapIdx::FIRST);
+    let mut c = ClampIdx::FIRST;
+    c -= 1;
+    assert_eq!(c, ClampIdx::FIRST);
+}
+
+#[test]
+#[should_panic]
+fn test_policy_panic_add_assign() {
+    let mut i = PanicIdx::LAST;
+    i += 1;
+}
+
+#[test]
+fn test_neighbors() {
+    enum CellMarker {}
+    type Cell = Andex<CellMarker, 5>;
+    type Cells = andex_array!(Cell, u32);
+    let cells = Cells::from([0, 10, 0, 0, 20]);
+    let smoothed = Cell::iter()
+        .map(|i| {
+            let (prev, next) = i.neighbors();
+            let sides = [prev, next].into_iter().flatten().map(|j| cells[j]);
+            cells[i] + sides.sum::<u32>()
+        })
+        .collect::<Cells>();
+    assert_eq!(smoothed, [10, 10, 10, 20, 20]);
+    assert_eq!(C::LAST.neighbors(), (Some(C::new::<1>()), None));
+    enum SingleMarker {}
+    type Single = Andex<SingleMarker, 1>;
+    assert_eq!(Single::FIRST.neighbors(), (None, None));
+}
@@ -0,0 +1,66 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::io;
+
+use anyhow::Result;
+
+/* Tests for binary streaming: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 3>;
+type MyU32 = andex_array!(MyIdx, u32);
+type MyF64 = andex_array!(MyIdx, f64);
+
+#[test]
+fn test_binary_roundtrip() -> Result<()> {
+    let myu32 = MyU32::from([1, 0x0203, u32::MAX]);
+    let mut bytes = Vec::new();
+    myu32.write_to(&mut bytes, Endian::Little)?;
+    assert_eq!(bytes.len(), 12);
+    assert_eq!(&bytes[4..8], &[3, 2, 0, 0]);
+    assert_eq!(
+        MyU32::read_from(io::Cursor::new(&bytes), Endian::Little)?,
+        myu32
+    );
+    let myf64 = MyF64::from([0.5, -1.0, 1e10]);
+    let mut bytes = Vec::new();
+    myf64.write_to(&mut bytes, Endian::Big)?;
+    assert_eq!(MyF64::read_from(&bytes[..], Endian::Big)?, myf64);
+    Ok(())
+}
+
+#[test]
+fn test_binary_short() {
+    let bytes = [0_u8; 11];
+    let err = MyU32::read_from(&bytes[..], Endian::Little).unwrap_err();
+    assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+}
+
+#[test]
+fn test_binary_usize() -> Result<()> {
+    type MyUsize = andex_array!(MyIdx, usize);
+    type MyIsize = andex_array!(MyIdx, isize);
+    let myusize = MyUsize::from([1, 2, 3]);
+    let mut bytes = Vec::new();
+    myusize.write_to(&mut bytes, Endian::Little)?;
+    assert_eq!(bytes.len(), 3 * 8);
+    assert_eq!(&bytes[8..16], &[2, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(MyUsize::read_from(&bytes[..], Endian::Little)?, myusize);
+    let myisize = MyIsize::from([-1, 0, isize::MIN]);
+    let mut bytes = Vec::new();
+    myisize.write_to(&mut bytes, Endian::Big)?;
+    assert_eq!(&bytes[..8], &[0xff; 8]);
+    assert_eq!(MyIsize::read_from(&bytes[..], Endian::Big)?, myisize);
+    let wide = [0xff_u8; 3 * 8];
+    let result = MyUsize::read_from(&wide[..], Endian::Little);
+    if cfg!(target_pointer_width = "64") {
+        assert_eq!(result?, MyUsize::from([usize::MAX; 3]));
+    } else {
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+    }
+    Ok(())
+}
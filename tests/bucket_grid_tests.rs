@@ -0,0 +1,109 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for BucketGrid: */
+
+pub struct CellMarker;
+type Cell = Andex<CellMarker, 12>;
+type Grid = BucketGrid<CellMarker, u32, 4, { Cell::SIZE }>;
+
+#[test]
+fn test_bucket_grid() {
+    let mut grid = Grid::default();
+    assert_eq!(Grid::HEIGHT, 3);
+    assert!(grid.cell_at(4, 0).is_none());
+    assert!(grid.cell_at(0, 3).is_none());
+    assert!(grid.insert_at(5, 5, 9).is_none());
+    let cell = grid.insert_at(3, 1, 1).unwrap();
+    assert_eq!(usize::from(cell), 7);
+    assert_eq!(grid.position(cell), (3, 1));
+    grid.insert(cell, 2);
+    assert_eq!(grid.bucket(cell), &[1, 2]);
+    assert_eq!(grid.iter().filter(|(_, b)| !b.is_empty()).count(), 1);
+    assert_eq!(grid.drain(cell).collect::<Vec<_>>(), vec![1, 2]);
+    assert!(grid.bucket(cell).is_empty());
+    grid.bucket_mut(Cell::FIRST).push(3);
+    grid.clear();
+    assert!(grid.bucket(Cell::FIRST).is_empty());
+}
+
+#[test]
+fn test_bucket_grid_neighborhood() {
+    let grid = Grid::new();
+    let corner = grid
+        .neighborhood(Cell::FIRST)
+        .map(usize::from)
+        .collect::<Vec<_>>();
+    assert_eq!(corner, vec![0, 1, 4, 5]);
+    let center = grid.cell_at(1, 1).unwrap();
+    let all = grid
+        .neighborhood(center)
+        .map(usize::from)
+        .collect::<Vec<_>>();
+    assert_eq!(all, vec![0, 1, 2, 4, 5, 6, 8, 9, 10]);
+    assert_eq!(grid.neighborhood(Cell::LAST).count(), 4);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_bucket_grid_serde() -> anyhow::Result<()> {
+    let mut grid = Grid::new();
+    grid.insert_at(1, 2, 5);
+    grid.insert_at(1, 2, 6);
+    grid.insert_at(3, 0, 7);
+    let buckets = |grid: &Grid| {
+        grid.iter()
+            .map(|(_, bucket)| bucket.to_vec())
+            .collect::<Vec<_>>()
+    };
+    let json = serde_json::to_string(&grid)?;
+    assert!(json.starts_with(r#"{"width":4,"height":3,"cells":[[],[],[],[7],"#));
+    let back = serde_json::from_str::<Grid>(&json)?;
+    assert_eq!(buckets(&back), buckets(&grid));
+    let wrong = json.replace(r#""width":4,"height":3"#, r#""width":3,"height":4"#);
+    assert!(serde_json::from_str::<Grid>(&wrong).is_err());
+    let expected = buckets(&grid);
+    let json = serde_json::to_string(&NestedGrid(grid))?;
+    assert_eq!(json, "[[[],[],[],[7]],[[],[],[],[]],[[],[5,6],[],[]]]");
+    let NestedGrid(back) = serde_json::from_str::<NestedGrid<Grid>>(&json)?;
+    assert_eq!(buckets(&back), expected);
+    let wrong = "[[[],[],[],[7]],[[],[],[],[]],[[],[5,6],[]]]";
+    assert!(serde_json::from_str::<NestedGrid<Grid>>(wrong).is_err());
+    Ok(())
+}
+
+pub struct TileMarker;
+type Tile = Andex<TileMarker, 6>;
+
+pub struct InTileMarker;
+type InTile = Andex<InTileMarker, 2>;
+
+#[test]
+fn test_bucket_grid_tiles() {
+    let mut grid = Grid::new();
+    for cell in Cell::iter() {
+        grid.insert(cell, usize::from(cell) as u32);
+    }
+    // 2x1 tiles over the 4x3 grid:
+    let tiles = grid
+        .tiles::<2, 1, TileMarker, 6, InTileMarker, 2>()
+        .map(|(tile, view)| {
+            assert_eq!(
+                view.cell(InTile::FIRST),
+                grid.cell_at(usize::from(tile) % 2 * 2, usize::from(tile) / 2)
+                    .unwrap()
+            );
+            (
+                tile,
+                view.iter().map(|(_, bucket)| bucket[0]).collect::<Vec<_>>(),
+            )
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(tiles.len(), Tile::SIZE);
+    assert_eq!(tiles[0], (Tile::FIRST, vec![0, 1]));
+    assert_eq!(tiles[1], (Tile::new::<1>(), vec![2, 3]));
+    assert_eq!(tiles[5], (Tile::LAST, vec![10, 11]));
+}
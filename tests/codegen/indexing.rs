@@ -0,0 +1,41 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+// Compiled by tests/codegen_tests.rs; the functions below are checked
+// for bound-check panics in the generated LLVM IR.
+
+#![allow(dead_code)]
+
+#[path = "../../src/andex.rs"]
+mod andex;
+
+use andex::*;
+
+pub enum MyIdxMarker {}
+pub type MyIdx = Andex<MyIdxMarker, 12>;
+pub type MyU32 = AndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+
+#[no_mangle]
+pub fn andex_index(array: &MyU32, idx: MyIdx) -> u32 {
+    array[idx]
+}
+
+#[no_mangle]
+pub fn andex_index_mut(array: &mut MyU32, idx: MyIdx, value: u32) {
+    array[idx] = value;
+}
+
+#[no_mangle]
+pub fn andex_iter_sum(array: &MyU32) -> u32 {
+    let mut sum = 0_u32;
+    for idx in MyIdx::iter() {
+        sum = sum.wrapping_add(array[idx]);
+    }
+    sum
+}
+
+#[no_mangle]
+pub fn usize_index(array: &[u32; 12], idx: usize) -> u32 {
+    array[idx]
+}
@@ -0,0 +1,63 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use std::env;
+use std::fs;
+use std::process::Command;
+
+use anyhow::{ensure, Result};
+
+/* Codegen tests: check that indexing is zero-cost */
+
+/// Compiles tests/codegen/indexing.rs with optimizations and returns
+/// the generated LLVM IR
+fn indexing_llvm_ir() -> Result<String> {
+    let manifest_dir = env!("CARGO_MANIFEST_DIR");
+    let outdir = env::temp_dir().join(format!("andex-codegen-{}", std::process::id()));
+    fs::create_dir_all(&outdir)?;
+    let output = outdir.join("indexing.ll");
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let status = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "lib",
+            "-C",
+            "opt-level=3",
+        ])
+        .args(["-C", "debug-assertions=off", "--emit", "llvm-ir", "-o"])
+        .arg(&output)
+        .arg(format!("{}/tests/codegen/indexing.rs", manifest_dir))
+        .status()?;
+    ensure!(status.success(), "rustc failed");
+    let ir = fs::read_to_string(&output)?;
+    fs::remove_dir_all(&outdir)?;
+    Ok(ir)
+}
+
+/// Returns the body of the provided function in the LLVM IR
+fn function_body<'a>(ir: &'a str, name: &str) -> &'a str {
+    let start = ir
+        .find(&format!("@{}(", name))
+        .unwrap_or_else(|| panic!("function {} not found", name));
+    let end = ir[start..].find("\n}").expect("function end not found");
+    &ir[start..start + end]
+}
+
+#[test]
+fn test_codegen_no_bound_checks() -> Result<()> {
+    let ir = indexing_llvm_ir()?;
+    // Sanity check: plain usize indexing has the bound check
+    assert!(function_body(&ir, "usize_index").contains("panic_bounds_check"));
+    for name in ["andex_index", "andex_index_mut", "andex_iter_sum"] {
+        let body = function_body(&ir, name);
+        assert!(!body.contains("panic"), "{} can panic:\n{}", name, body);
+        let has_calls = body
+            .lines()
+            .any(|line| line.contains("call ") && !line.contains("@llvm."));
+        assert!(!has_calls, "{} has calls:\n{}", name, body);
+    }
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::convert::TryFrom;
+use std::mem::size_of;
+
+use anyhow::Result;
+
+/* Tests for CompactAndex: */
+
+enum Marker {}
+type Entity = Andex<Marker, 1000>;
+type Entity16 = CompactAndex<Marker, u16, { Entity::SIZE }>;
+type Entity32 = CompactAndex<Marker, u32, { Entity::SIZE }>;
+type Health = andex_array!(Entity, u32);
+
+#[test]
+fn test_compact_andex() -> Result<()> {
+    assert_eq!(size_of::<Entity16>(), 2);
+    assert_eq!(size_of::<Option<Entity16>>(), 4);
+    assert_eq!(size_of::<[Entity32; 10]>(), 40);
+    let e = Entity16::try_from(999)?;
+    assert_eq!(Entity::from(e), Entity::LAST);
+    assert_eq!(usize::from(e), 999);
+    assert!(Entity16::try_from(1000).is_err());
+    assert_eq!(
+        "12".parse::<Entity16>()?,
+        Entity16::from(Entity::new::<12>())
+    );
+    assert_eq!(e.to_string(), "999");
+    assert_eq!(format!("{:#}", e), "999 of 1000");
+    assert_eq!(format!("{:?}", e), "compact_tests::Marker(999/1000)");
+    assert!(Entity16::default() < e);
+    assert_eq!(Entity32::iter().count(), 1000);
+    let mut health = Health::default();
+    health[e] = 3;
+    health[Entity32::from(Entity::FIRST)] = 1;
+    assert_eq!(health[Entity::LAST], 3);
+    assert_eq!(health[Entity::FIRST], 1);
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for andex_consts: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 12>;
+type MyArray = andex_array!(MyIdx, u32);
+
+andex_consts!(MyIdx {
+    HEAD = 0,
+    /// Constant with a doc comment
+    MIDDLE = MyIdx::SIZE / 2,
+    TAIL = 11
+});
+
+mod inner {
+    use super::MyIdx;
+    use andex::andex_consts;
+    andex_consts!(pub(crate) MyIdx { SECOND = 1 });
+}
+
+#[test]
+fn test_consts() {
+    assert_eq!(HEAD, MyIdx::FIRST);
+    assert_eq!(usize::from(MIDDLE), 6);
+    assert_eq!(TAIL, MyIdx::LAST);
+    assert_eq!(inner::SECOND, HEAD.next().unwrap());
+    let mut array = MyArray::default();
+    array[TAIL] = 5;
+    assert_eq!(array[MyIdx::LAST], 5);
+}
@@ -0,0 +1,35 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for Cursor: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 3>;
+
+#[test]
+fn test_cursor() {
+    let mut cursor = Cursor::<MyIdx>::new();
+    assert_eq!(cursor.current(), None);
+    let values = cursor.by_ref().take(7).map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values, vec![0, 1, 2, 0, 1, 2, 0]);
+    assert_eq!(cursor.peek(), MyIdx::new::<1>());
+    cursor.advance(4);
+    assert_eq!(cursor.current(), Some(MyIdx::new::<1>()));
+    cursor.advance(0);
+    assert_eq!(cursor.next(), Some(MyIdx::LAST));
+    cursor.reset();
+    cursor.advance(1);
+    assert_eq!(cursor.current(), Some(MyIdx::FIRST));
+    cursor.reset();
+    cursor.advance(usize::MAX);
+    assert_eq!(
+        cursor.current(),
+        MyIdx::FIRST.checked_add((usize::MAX - 1) % 3)
+    );
+    let mut cursor = Cursor::after(MyIdx::LAST);
+    assert_eq!(cursor.next(), Some(MyIdx::FIRST));
+    assert_eq!(cursor.size_hint(), (usize::MAX, None));
+}
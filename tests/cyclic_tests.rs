@@ -0,0 +1,52 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::convert::TryFrom;
+
+use anyhow::Result;
+
+/* Tests for CyclicAndex: */
+
+enum Marker {}
+type Turn = CyclicAndex<Marker, 3>;
+type Plain = Andex<Marker, 3>;
+type Scores = AndexableArray<Turn, u32, { Turn::SIZE }>;
+
+#[test]
+fn test_cyclic_andex() -> Result<()> {
+    assert_eq!(Turn::LAST.next(), Turn::FIRST);
+    assert_eq!(Turn::FIRST.prev(), Turn::LAST);
+    assert_eq!(Turn::FIRST + 4, Turn::new::<1>());
+    assert_eq!(Turn::FIRST - 4, Turn::new::<2>());
+    assert_eq!(Turn::LAST + usize::MAX, Turn::LAST + (usize::MAX % 3));
+    let mut turn = Turn::default();
+    turn -= 1;
+    assert_eq!(turn, Turn::LAST);
+    turn += 2;
+    assert_eq!(turn, Turn::new::<1>());
+    assert_eq!(Turn::try_from(2)?, Turn::LAST);
+    assert!(Turn::try_from(3).is_err());
+    assert_eq!("1".parse::<Turn>()?, turn);
+    assert_eq!(turn.to_string(), "1");
+    assert_eq!(Plain::from(turn), Plain::new::<1>());
+    assert_eq!(Turn::from(Plain::LAST), Turn::LAST);
+    assert_eq!(
+        Turn::iter().map(usize::from).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_round_robin() {
+    let mut scores = Scores::default();
+    let mut turn = Turn::FIRST;
+    for _ in 0..7 {
+        scores[turn] += 1;
+        turn = turn.next();
+    }
+    assert_eq!(scores, [3, 2, 2]);
+}
@@ -0,0 +1,98 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "derive")]
+
+use andex::*;
+
+use std::collections::HashSet;
+use std::convert::TryFrom;
+
+/* Tests for derive(Andexable): */
+
+#[derive(Andexable, Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    Red,
+    Green,
+    /// Variant with an attribute
+    Blue,
+}
+
+type ColorAndex = Andex<Color, { Color::SIZE }>;
+type Intensity = AndexableArray<Color, u32, { Color::SIZE }>;
+
+#[derive(Andexable, Debug, PartialEq, Eq)]
+pub enum Discriminants {
+    First = 10,
+    Second = 5,
+}
+
+#[test]
+fn test_derive() {
+    assert_eq!(Color::SIZE, 3);
+    assert_eq!(ColorAndex::from(Color::Red), ColorAndex::FIRST);
+    assert_eq!(ColorAndex::from(Color::Blue), ColorAndex::LAST);
+    assert_eq!(Color::from(ColorAndex::new::<1>()), Color::Green);
+    let colors = ColorAndex::iter().map(Color::from).collect::<Vec<_>>();
+    assert_eq!(colors, vec![Color::Red, Color::Green, Color::Blue]);
+}
+
+#[test]
+fn test_derive_index() {
+    let mut intensity = Intensity::default();
+    intensity[Color::Green] = 5;
+    intensity[ColorAndex::LAST] = 7;
+    assert_eq!(intensity, [0, 5, 7]);
+    assert_eq!(intensity[Color::Blue], 7);
+}
+
+#[test]
+fn test_derive_discriminants() {
+    assert_eq!(Discriminants::SIZE, 2);
+    assert_eq!(usize::from(Discriminants::Second.andex()), 1);
+    assert_eq!(
+        Discriminants::from(Andex::<Discriminants, 2>::FIRST),
+        Discriminants::First
+    );
+}
+
+/* Tests for derive(AndexNewtype): */
+
+enum PlayerMarker {}
+type PlayerIdx = Andex<PlayerMarker, 4>;
+
+/// Newtype used in the tests
+#[derive(AndexNewtype)]
+pub(crate) struct PlayerId(pub PlayerIdx);
+
+impl PlayerId {
+    fn is_host(self) -> bool {
+        self.0 == PlayerIdx::FIRST
+    }
+}
+
+#[test]
+fn test_derive_newtype() -> Result<(), Error> {
+    let id = PlayerId::try_from(3)?;
+    assert_eq!(id, PlayerId::from(PlayerIdx::LAST));
+    assert_eq!(PlayerIdx::from(id), PlayerIdx::LAST);
+    assert_eq!(usize::from(id), 3);
+    assert!(PlayerId::try_from(4).is_err());
+    assert!(PlayerId(PlayerIdx::FIRST).is_host());
+    assert!(PlayerId(PlayerIdx::FIRST) < id);
+    assert_eq!(id.to_string(), "3");
+    assert_eq!(
+        format!("{:?}", id),
+        format!("PlayerId({:?})", PlayerIdx::LAST)
+    );
+    assert_eq!("1".parse::<PlayerId>()?, PlayerId(PlayerIdx::new::<1>()));
+    let set = PlayerIdx::iter()
+        .map(PlayerId::from)
+        .collect::<HashSet<_>>();
+    assert!(set.contains(&id));
+    let mut scores = AndexableArray::<PlayerId, u32, { PlayerIdx::SIZE }>::default();
+    scores[id] = 5;
+    assert_eq!(scores[PlayerIdx::LAST], 5);
+    Ok(())
+}
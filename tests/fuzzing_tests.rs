@@ -0,0 +1,29 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "arbitrary")]
+
+use andex::*;
+
+use arbitrary::{Arbitrary, Unstructured};
+
+/* Tests for the arbitrary integration: */
+
+enum Marker {}
+type C = Andex<Marker, 10>;
+
+#[test]
+fn test_arbitrary() {
+    let data = (0..=255).collect::<Vec<u8>>();
+    let mut u = Unstructured::new(&data);
+    let mut seen = [false; 10];
+    while !u.is_empty() {
+        seen[usize::from(C::arbitrary(&mut u).unwrap())] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+    let mut empty = Unstructured::new(&[]);
+    assert_eq!(C::arbitrary(&mut empty).unwrap(), C::FIRST);
+    let (from, to) = <(C, C)>::arbitrary(&mut Unstructured::new(&[3, 250])).unwrap();
+    assert!(from <= C::LAST && to <= C::LAST);
+}
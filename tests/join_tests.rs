@@ -0,0 +1,48 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for join: */
+
+pub struct EntityIdMarker;
+type EntityId = Andex<EntityIdMarker, 5>;
+
+type Positions = andex_array!(EntityId, i32);
+type Velocities = andex_array!(EntityId, Option<i32>);
+type Alive = andex_array!(EntityId, bool);
+
+#[test]
+fn test_join_dense() {
+    let positions = Positions::from([0, 1, 2, 3, 4]);
+    let mut speeds = Positions::from([1; 5]);
+    for (entity, pos, speed) in join!(&positions, &mut speeds) {
+        assert_eq!(usize::from(entity) as i32, *pos);
+        *speed += pos;
+    }
+    assert_eq!(speeds.as_ref(), &[1, 2, 3, 4, 5]);
+    assert_eq!(join!(&positions).count(), 5);
+}
+
+#[test]
+fn test_join_sparse() {
+    let positions = Positions::from([0, 1, 2, 3, 4]);
+    let mut velocities = Velocities::from([Some(1), None, Some(1), None, Some(1)]);
+    let alive = Alive::from([true, true, false, false, true]);
+    let joined = join!(&positions, Present(&mut velocities), Mask(&alive))
+        .map(|(entity, _, vel, ())| {
+            *vel = 7;
+            usize::from(entity)
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(joined, vec![0, 4]);
+    assert_eq!(
+        velocities.as_ref(),
+        &[Some(7), None, Some(1), None, Some(7)]
+    );
+    let present = join((Present(&velocities),))
+        .map(|(entity, _)| usize::from(entity))
+        .collect::<Vec<_>>();
+    assert_eq!(present, vec![0, 2, 4]);
+}
@@ -0,0 +1,61 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::sync::{Arc, Mutex, RwLock};
+use std::thread;
+
+/* Tests for the lock helpers: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 2>;
+type Mutexes = andex_array!(MyIdx, Mutex<u32>);
+type RwLocks = andex_array!(MyIdx, RwLock<u32>);
+
+#[test]
+fn test_lock_two() {
+    let array = Arc::new(Mutexes::from([Mutex::new(0), Mutex::new(0)]));
+    let threads = (0..4)
+        .map(|t| {
+            let array = Arc::clone(&array);
+            thread::spawn(move || {
+                for _ in 0..100 {
+                    let (a, b) = if t % 2 == 0 {
+                        (MyIdx::FIRST, MyIdx::LAST)
+                    } else {
+                        (MyIdx::LAST, MyIdx::FIRST)
+                    };
+                    let (mut ga, mut gb) = array.lock_two(a, b).unwrap();
+                    *ga += 1;
+                    *gb += 2;
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    for t in threads {
+        t.join().unwrap();
+    }
+    assert_eq!(*array.lock(MyIdx::FIRST).unwrap(), 600);
+    assert_eq!(*array.lock(MyIdx::LAST).unwrap(), 600);
+}
+
+#[test]
+#[should_panic]
+fn test_lock_two_same() {
+    let array = Mutexes::from([Mutex::new(0), Mutex::new(0)]);
+    let _ = array.lock_two(MyIdx::FIRST, MyIdx::FIRST);
+}
+
+#[test]
+fn test_rwlock() {
+    let array = RwLocks::from([RwLock::new(1), RwLock::new(2)]);
+    {
+        let (mut b, mut a) = array.write_two(MyIdx::LAST, MyIdx::FIRST).unwrap();
+        std::mem::swap(&mut *a, &mut *b);
+    }
+    *array.write(MyIdx::FIRST).unwrap() += 10;
+    assert_eq!(*array.read(MyIdx::FIRST).unwrap(), 12);
+    assert_eq!(*array.read(MyIdx::LAST).unwrap(), 1);
+}
@@ -0,0 +1,40 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for LruCache: */
+
+enum Marker {}
+type Slot = Andex<Marker, 3>;
+type Cache = LruCache<Slot, &'static str, usize, { Slot::SIZE }>;
+
+#[test]
+fn test_lru_cache() {
+    let mut cache = Cache::default();
+    assert!(cache.is_empty());
+    let a = cache.get_or_insert_with("a", |k| k.len());
+    let b = cache.get_or_insert_with("bb", |k| k.len());
+    let c = cache.get_or_insert_with("ccc", |k| k.len());
+    assert_eq!(vec![a, b, c], Slot::iter().collect::<Vec<_>>());
+    assert_eq!(cache.len(), 3);
+    // Touch "a", so that "bb" is the least recently used:
+    assert_eq!(cache.get(&"a"), Some(&1));
+    assert_eq!(cache.get_or_insert_with("a", |_| 9), a);
+    let d = cache.get_or_insert_with("dddd", |k| k.len());
+    assert_eq!(d, b);
+    assert_eq!(cache[d], 4);
+    assert_eq!(cache.slot_of(&"bb"), None);
+    assert_eq!(cache.len(), 3);
+    cache[a] += 10;
+    assert_eq!(cache.get_slot(a), Some((&"a", &11)));
+    let entries = cache
+        .iter()
+        .map(|(s, k, v)| (s, *k, *v))
+        .collect::<Vec<_>>();
+    assert_eq!(entries, vec![(a, "a", 11), (b, "dddd", 4), (c, "ccc", 3)]);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.get_slot(a), None);
+}
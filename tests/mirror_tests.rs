@@ -0,0 +1,41 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for andex_enum: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 3>;
+
+andex_enum! {
+    /// Enum used in the tests
+    enum Stage(MyIdx) {
+        Start,
+        /// Variant with a doc comment
+        Middle,
+        End,
+    }
+}
+
+fn name(stage: Stage) -> &'static str {
+    match stage {
+        Stage::Start => "start",
+        Stage::Middle => "middle",
+        Stage::End => "end",
+    }
+}
+
+#[test]
+fn test_enum() {
+    let stages = MyIdx::iter().map(Stage::from).collect::<Vec<_>>();
+    assert_eq!(stages, vec![Stage::Start, Stage::Middle, Stage::End]);
+    assert_eq!(name(Stage::from(MyIdx::LAST)), "end");
+    assert_eq!(MyIdx::from(Stage::Middle), MyIdx::new::<1>());
+    assert_eq!(usize::from(Stage::End), 2);
+    assert!(Stage::Start < Stage::End);
+    let mut array = AndexableArray::<Stage, u32, { MyIdx::SIZE }>::default();
+    array[Stage::Middle] = 5;
+    assert_eq!(array[MyIdx::new::<1>()], 5);
+}
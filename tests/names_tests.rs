@@ -0,0 +1,35 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use anyhow::Result;
+
+/* Tests for andex names: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 3>;
+
+impl AndexNames<3> for Marker {
+    const NAMES: [&'static str; 3] = ["Red", "Green", "Blue"];
+}
+
+#[test]
+fn test_names() -> Result<()> {
+    assert_eq!(MyIdx::LAST.name(), "Blue");
+    assert_eq!(MyIdx::from_name("Green")?, MyIdx::new::<1>());
+    assert!(matches!(
+        MyIdx::from_name("green"),
+        Err(Error::UnknownName(name)) if name == "green"
+    ));
+    let names = MyIdx::iter()
+        .map(|i| Named(i).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(names, vec!["Red", "Green", "Blue"]);
+    assert_eq!("Red".parse::<Named<MyIdx>>()?, Named(MyIdx::FIRST));
+    assert_eq!("2".parse::<Named<MyIdx>>()?, Named(MyIdx::LAST));
+    assert!("3".parse::<Named<MyIdx>>().is_err());
+    assert!("blue".parse::<Named<MyIdx>>().is_err());
+    Ok(())
+}
@@ -0,0 +1,38 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "num-traits")]
+
+use andex::*;
+
+use num_traits::{Bounded, FromPrimitive, NumCast, ToPrimitive};
+
+/* Tests for the num-traits integration: */
+
+enum Marker {}
+type C = Andex<Marker, 10>;
+
+fn span<T: Bounded + ToPrimitive>() -> Option<u64> {
+    Some(T::max_value().to_u64()? - T::min_value().to_u64()?)
+}
+
+#[test]
+fn test_bounded() {
+    assert_eq!(C::min_value(), C::FIRST);
+    assert_eq!(C::max_value(), C::LAST);
+    assert_eq!(span::<C>(), Some(9));
+}
+
+#[test]
+fn test_primitive() {
+    assert_eq!(C::LAST.to_u8(), Some(9));
+    assert_eq!(C::LAST.to_i64(), Some(9));
+    assert_eq!(C::LAST.to_f64(), Some(9.0));
+    assert_eq!(C::from_u8(9), Some(C::LAST));
+    assert_eq!(C::from_u64(10), None);
+    assert_eq!(C::from_i8(-1), None);
+    assert_eq!(C::from_f32(0.5), Some(C::FIRST));
+    assert_eq!(C::from_f32(f32::NAN), None);
+    assert_eq!(<u16 as NumCast>::from(C::LAST), Some(9));
+}
@@ -0,0 +1,47 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::convert::TryFrom;
+
+use anyhow::Result;
+
+/* Tests for OffsetAndex: */
+
+enum Marker {}
+type Die = OffsetAndex<Marker, 1, 6>;
+type Counts = AndexableArray<Die, u32, { Die::SIZE }>;
+
+#[test]
+fn test_offset_andex() -> Result<()> {
+    assert_eq!((Die::MIN_VALUE, Die::MAX_VALUE), (1, 6));
+    let values = Die::iter().map(usize::from).collect::<Vec<_>>();
+    assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    assert_eq!(Die::try_from(6)?, Die::LAST);
+    assert!(matches!(
+        Die::try_from(0),
+        Err(Error::OutOfRange {
+            value: 0,
+            min: 1,
+            max: 6
+        })
+    ));
+    assert_eq!(
+        Die::try_from(7).unwrap_err().to_string(),
+        "value 7 is out-of-bounds, valid values are 1 to 6"
+    );
+    assert!("9".parse::<Die>().is_err());
+    assert_eq!(format!("{:?}", Die::LAST), "offset_tests::Marker(6/1..=6)");
+    assert_eq!("1".parse::<Die>()?, Die::FIRST);
+    assert_eq!(Die::new::<3>().to_string(), "3");
+    assert!(Die::FIRST < Die::LAST);
+    let mut counts = Counts::default();
+    for roll in [1, 6, 6, 3] {
+        counts[Die::try_from(roll)?] += 1;
+    }
+    assert_eq!(counts, [1, 0, 1, 0, 0, 2]);
+    assert_eq!(counts[Die::new::<6>().andex()], 2);
+    Ok(())
+}
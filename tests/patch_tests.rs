@@ -0,0 +1,47 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for Patch: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 5>;
+type MyArray = andex_array!(MyIdx, u32);
+type MyPatch = Patch<MyIdx, u32>;
+
+#[test]
+fn test_patch() {
+    let old = MyArray::from([1, 2, 3, 4, 5]);
+    let new = MyArray::from([1, 0, 3, 0, 5]);
+    let patch = MyPatch::from_diff(&old, &new);
+    assert_eq!(
+        patch
+            .iter()
+            .map(|(i, v)| (usize::from(i), *v))
+            .collect::<Vec<_>>(),
+        vec![(1, 0), (3, 0)]
+    );
+    let mut patched = old;
+    patched.apply_patch(patch.clone());
+    assert_eq!(patched, new);
+    assert!(MyPatch::from_diff(&new, &patched).is_empty());
+    let mut manual = MyPatch::default();
+    manual.push(MyIdx::LAST, 9);
+    patched.apply_patch(manual);
+    assert_eq!(patched.as_ref(), &[1, 0, 3, 0, 9]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_patch_serde() -> anyhow::Result<()> {
+    let mut patch = MyPatch::new();
+    patch.push(MyIdx::new::<2>(), 7);
+    let json = serde_json::to_string(&patch)?;
+    assert_eq!(json, "[[2,7]]");
+    let back = serde_json::from_str::<MyPatch>(&json)?;
+    assert_eq!(back.len(), 1);
+    assert!(serde_json::from_str::<MyPatch>("[[5,7]]").is_err());
+    Ok(())
+}
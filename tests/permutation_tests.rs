@@ -0,0 +1,55 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::convert::TryFrom;
+
+/* Tests for Permutation: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 6>;
+type MyArray = andex_array!(MyIdx, u32);
+type MyMapping = andex_array!(MyIdx, MyIdx);
+type MyPermutation = Permutation<MyIdxInner, { MyIdx::SIZE }>;
+
+fn mapping(values: [usize; 6]) -> MyMapping {
+    values
+        .iter()
+        .map(|&v| MyIdx::try_from(v).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_permutation() {
+    assert!(MyPermutation::new(mapping([0, 1, 2, 3, 4, 4])).is_none());
+    let permutation = MyPermutation::new(mapping([1, 2, 0, 5, 3, 4])).unwrap();
+    assert_eq!(permutation[MyIdx::FIRST], MyIdx::new::<1>());
+    let mut myarray = MyArray::from([10, 11, 12, 13, 14, 15]);
+    permutation.apply(&mut myarray);
+    assert_eq!(myarray.as_ref(), &[11, 12, 10, 15, 13, 14]);
+    permutation.inverse().apply(&mut myarray);
+    assert_eq!(myarray.as_ref(), &[10, 11, 12, 13, 14, 15]);
+    MyPermutation::identity().apply(&mut myarray);
+    assert_eq!(myarray.as_ref(), &[10, 11, 12, 13, 14, 15]);
+    assert_eq!(MyPermutation::default(), MyPermutation::identity());
+    assert_eq!(permutation.inverse().inverse(), permutation.clone());
+    assert_eq!(permutation.iter().map(usize::from).sum::<usize>(), 15);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_permutation_random() {
+    use rand::SeedableRng;
+    let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+    let permutation = MyPermutation::random(&mut rng);
+    let mut seen = permutation.iter().map(usize::from).collect::<Vec<_>>();
+    seen.sort();
+    assert_eq!(seen, vec![0, 1, 2, 3, 4, 5]);
+    let mut myarray = MyArray::from([1, 2, 3, 4, 5, 6]);
+    myarray.shuffle(&mut rng);
+    let mut items = myarray.iter().copied().collect::<Vec<_>>();
+    items.sort();
+    assert_eq!(items, vec![1, 2, 3, 4, 5, 6]);
+}
@@ -0,0 +1,37 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "proptest")]
+
+use andex::*;
+
+use proptest::prelude::*;
+use proptest::strategy::ValueTree;
+use proptest::test_runner::TestRunner;
+
+/* Tests for the proptest integration: */
+
+enum Marker {}
+type C = Andex<Marker, 10>;
+type MyArray = andex_array!(C, u32);
+
+proptest! {
+    #[test]
+    fn test_proptest_in_bounds(idx in any::<C>(), value in any::<u32>()) {
+        let mut array = MyArray::default();
+        array[idx] = value;
+        prop_assert!(idx <= C::LAST);
+        prop_assert_eq!(array[idx], value);
+    }
+}
+
+#[test]
+fn test_proptest_shrinks_to_first() {
+    let mut runner = TestRunner::deterministic();
+    for _ in 0..20 {
+        let mut tree = any::<C>().new_tree(&mut runner).unwrap();
+        while tree.simplify() {}
+        assert_eq!(tree.current(), C::FIRST);
+    }
+}
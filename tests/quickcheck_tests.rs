@@ -0,0 +1,44 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "quickcheck")]
+
+use andex::*;
+
+use quickcheck::{quickcheck, Arbitrary, Gen};
+
+/* Tests for the quickcheck integration: */
+
+enum Marker {}
+type C = Andex<Marker, 10>;
+type MyArray = andex_array!(C, u32);
+
+fn prop_index_in_bounds(idx: C, value: u32) -> bool {
+    let mut array = MyArray::default();
+    array[idx] = value;
+    idx <= C::LAST && array[idx] == value
+}
+
+#[test]
+fn test_quickcheck_in_bounds() {
+    quickcheck(prop_index_in_bounds as fn(C, u32) -> bool);
+}
+
+#[test]
+fn test_quickcheck_generates_all() {
+    let mut g = Gen::new(100);
+    let mut seen = [false; 10];
+    for _ in 0..1000 {
+        seen[usize::from(C::arbitrary(&mut g))] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+}
+
+#[test]
+fn test_quickcheck_shrink() {
+    let shrunk = C::LAST.shrink().collect::<Vec<_>>();
+    assert!(shrunk.contains(&C::FIRST));
+    assert!(shrunk.iter().all(|&i| i < C::LAST));
+    assert_eq!(C::FIRST.shrink().count(), 0);
+}
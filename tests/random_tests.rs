@@ -0,0 +1,51 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "rand")]
+
+use andex::*;
+
+use rand::distr::{Distribution, Uniform};
+use rand::{RngExt, SeedableRng};
+
+/* Tests for the rand integration: */
+
+enum Marker {}
+type C = Andex<Marker, 10>;
+
+#[test]
+fn test_sample() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+    let mut sample = C::sample(&mut rng, 4);
+    assert_eq!(sample.len(), 4);
+    sample.sort();
+    sample.dedup();
+    assert_eq!(sample.len(), 4);
+    assert!(C::sample(&mut rng, 0).is_empty());
+    let mut all = C::sample(&mut rng, 20);
+    all.sort();
+    assert_eq!(all, C::iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn test_uniform() {
+    let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+    let mut seen = [false; 10];
+    for _ in 0..1000 {
+        seen[usize::from(rng.random::<C>())] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+    let low = C::new::<3>();
+    let high = C::new::<6>();
+    for _ in 0..100 {
+        let i = rng.random_range(low..high);
+        assert!(low <= i && i < high);
+        let j = rng.random_range(low..=high);
+        assert!(low <= j && j <= high);
+    }
+    assert_eq!(rng.random_range(high..=high), high);
+    let uniform = Uniform::new(C::FIRST, low).unwrap();
+    assert!(uniform.sample_iter(&mut rng).take(10).all(|i| i < low));
+    assert!(Uniform::new(low, low).is_err());
+}
@@ -0,0 +1,32 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for ReverseIndex: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 4>;
+type MyStrings = andex_array!(MyIdx, String);
+
+#[test]
+fn test_reverse_index() {
+    let array = ["a", "b", "a", "c"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect::<MyStrings>();
+    let mut reverse = ReverseIndex::from_array(&array);
+    assert_eq!(reverse.len(), 3);
+    assert_eq!(reverse.index_of("a"), Some(MyIdx::FIRST));
+    assert_eq!(reverse.index_of("c"), Some(MyIdx::LAST));
+    assert_eq!(reverse.index_of("d"), None);
+    assert_eq!(
+        reverse.insert("c".to_string(), MyIdx::FIRST),
+        Some(MyIdx::LAST)
+    );
+    assert_eq!(reverse.remove("b"), Some(MyIdx::new::<1>()));
+    assert_eq!(reverse.remove("b"), None);
+    let empty = ReverseIndex::<MyIdx, String>::default();
+    assert!(empty.is_empty());
+}
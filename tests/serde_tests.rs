@@ -0,0 +1,38 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "serde")]
+
+use andex::*;
+
+use anyhow::Result;
+
+/* Tests for the serde support of Andex: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 5>;
+
+#[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+struct SaveState {
+    player: MyIdx,
+    targets: Vec<MyIdx>,
+}
+
+#[test]
+fn test_andex_serde() -> Result<()> {
+    assert_eq!(serde_json::to_string(&MyIdx::LAST)?, "4");
+    assert_eq!(serde_json::from_str::<MyIdx>("2")?, MyIdx::new::<2>());
+    let state = SaveState {
+        player: MyIdx::new::<1>(),
+        targets: vec![MyIdx::FIRST, MyIdx::LAST],
+    };
+    let json = serde_json::to_string(&state)?;
+    assert_eq!(json, r#"{"player":1,"targets":[0,4]}"#);
+    assert_eq!(serde_json::from_str::<SaveState>(&json)?, state);
+    let err = serde_json::from_str::<MyIdx>("5").unwrap_err();
+    assert!(err.to_string().contains("out-of-bounds"));
+    assert!(serde_json::from_str::<MyIdx>("-1").is_err());
+    assert!(serde_json::from_str::<MyIdx>(r#""1""#).is_err());
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+#![cfg(feature = "serde")]
+
+use andex::*;
+
+use anyhow::Result;
+
+/* Tests for SparseArray: */
+
+enum Marker {}
+type MyIdx = Andex<Marker, 5>;
+type MyArray = andex_array!(MyIdx, Option<u32>);
+
+#[test]
+fn test_sparse_array() -> Result<()> {
+    let SparseArray(array) = serde_json::from_str::<SparseArray<MyArray>>(r#"{"4": 3}"#)?;
+    assert_eq!(array.as_ref(), &[None, None, None, None, Some(3)]);
+    let json = serde_json::to_string(&SparseArray(array))?;
+    assert_eq!(json, r#"{"0":null,"1":null,"2":null,"3":null,"4":3}"#);
+    let SparseArray(back) = serde_json::from_str::<SparseArray<MyArray>>(&json)?;
+    assert_eq!(back, array);
+    let SparseArray(empty) = serde_json::from_str::<SparseArray<MyArray>>("{}")?;
+    assert_eq!(empty, MyArray::default());
+    assert!(serde_json::from_str::<SparseArray<MyArray>>(r#"{"5": 1}"#).is_err());
+    Ok(())
+}
@@ -0,0 +1,36 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for TrackedAndexableArray: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 6>;
+type MyTracked = TrackedAndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+
+#[test]
+fn test_tracked() {
+    let mut t = MyTracked::from([1, 2, 3, 4, 5, 6]);
+    assert_eq!(t.iter_dirty().count(), 0);
+    assert_eq!(t[MyIdx::LAST], 6);
+    assert_eq!(t.iter_dirty().count(), 0);
+    t[MyIdx::new::<1>()] += 10;
+    t[&MyIdx::new::<4>()] = 0;
+    assert_eq!(t.replace(MyIdx::FIRST, 9), 1);
+    let dirty = t
+        .iter_dirty()
+        .map(|(i, v)| (usize::from(i), *v))
+        .collect::<Vec<_>>();
+    assert_eq!(dirty, vec![(0, 9), (1, 12), (4, 0)]);
+    assert!(t.is_dirty(MyIdx::FIRST));
+    assert!(!t.is_dirty(MyIdx::LAST));
+    let t2 = t.clone();
+    t.clear_dirty();
+    assert_eq!(t.iter_dirty().count(), 0);
+    assert_eq!(t2.iter_dirty().count(), 3);
+    t.mark_dirty(MyIdx::LAST);
+    assert_eq!(t.iter_dirty().count(), 1);
+    assert_eq!(t.into_inner().as_ref(), &[9, 12, 3, 4, 0, 6]);
+}
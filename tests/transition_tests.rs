@@ -0,0 +1,53 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for TransitionTable: */
+
+enum StateMarker {}
+type State = Andex<StateMarker, 3>;
+
+enum EventMarker {}
+type Event = Andex<EventMarker, 2>;
+
+type Table = TransitionTable<State, Event, { State::SIZE }, { Event::SIZE }>;
+
+#[test]
+fn test_transition_table() {
+    let next = Event::FIRST;
+    let stay = Event::LAST;
+    let mut builder = Table::builder();
+    for state in State::iter() {
+        let following = state.next().unwrap_or(State::FIRST);
+        builder = builder.on(state, next, following).on(state, stay, state);
+    }
+    let table = builder.build().unwrap();
+    let mut state = State::FIRST;
+    for _ in 0..4 {
+        state = table.step(state, next);
+    }
+    assert_eq!(state, State::new::<1>());
+    assert_eq!(table.step(state, stay), state);
+    assert_eq!(
+        table.transitions(State::LAST).as_ref(),
+        &[State::FIRST, State::LAST]
+    );
+}
+
+#[test]
+fn test_transition_table_missing() {
+    let err = Table::builder()
+        .on(State::FIRST, Event::FIRST, State::LAST)
+        .build()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        MissingTransition {
+            state: State::FIRST,
+            event: Event::LAST
+        }
+    );
+    assert_eq!(err.to_string(), "no transition from state 0 on event 1");
+}
@@ -0,0 +1,93 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+use std::cell::Cell;
+
+/* Tests for UndoableAndexableArray: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 3>;
+type MyArray = andex_array!(MyIdx, String);
+type MyUndoable = UndoableAndexableArray<MyIdx, String, { MyIdx::SIZE }>;
+
+fn strings(u: &MyUndoable) -> Vec<&str> {
+    u.as_ref().iter().map(String::as_str).collect()
+}
+
+#[test]
+fn test_undo_redo() {
+    let array = MyArray::from(["a".to_string(), "b".to_string(), "c".to_string()]);
+    let mut u = MyUndoable::new(array, 2);
+    assert!(!u.undo());
+    assert!(!u.redo());
+    u.edit(MyIdx::FIRST).push('1');
+    u.edit(MyIdx::FIRST).push('2');
+    u.edit(MyIdx::LAST).push('3');
+    assert_eq!(strings(&u), vec!["a12", "b", "c3"]);
+    assert_eq!(u.undo_len(), 2);
+    assert!(u.undo());
+    assert!(u.undo());
+    assert!(!u.undo());
+    assert_eq!(strings(&u), vec!["a1", "b", "c"]);
+    assert!(u.redo());
+    assert_eq!(u[MyIdx::FIRST], "a12");
+    assert_eq!(u.redo_len(), 1);
+    u.edit(MyIdx::FIRST).clear();
+    assert_eq!(u.redo_len(), 0);
+    assert!(!u.redo());
+    u.clear_history();
+    assert!(!u.undo());
+    assert_eq!(u.into_inner().as_ref(), &["", "b", "c"]);
+}
+
+#[test]
+fn test_snapshot() {
+    let mut u = MyUndoable::new(MyArray::from(std::array::from_fn(|_| String::new())), 0);
+    let snapshot = u.snapshot();
+    u.edit(MyIdx::FIRST).push('x');
+    assert_eq!(snapshot[MyIdx::FIRST], "");
+    assert!(!u.undo());
+    let snapshot2 = u.snapshot();
+    u.restore(&snapshot);
+    assert_eq!(u[MyIdx::FIRST], "");
+    u.restore(&snapshot2.clone());
+    assert_eq!(u[MyIdx::FIRST], "x");
+}
+
+thread_local! {
+    static CLONES: Cell<usize> = const { Cell::new(0) };
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Counted(u32);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Counted(self.0)
+    }
+}
+
+#[test]
+fn test_edit_clones() {
+    type Big = Andex<MyIdxInner, 100>;
+    type BigUndoable = UndoableAndexableArray<Big, Counted, { Big::SIZE }>;
+    let array = AndexableArray::from(std::array::from_fn(|_| Counted::default()));
+    let mut u = BigUndoable::new(array, 10);
+    for (value, i) in Big::iter().take(20).enumerate() {
+        u.edit(i).0 = value as u32 + 1;
+    }
+    assert_eq!(CLONES.with(Cell::get), 20);
+    while u.undo() {}
+    while u.redo() {}
+    assert_eq!(CLONES.with(Cell::get), 20);
+    assert_eq!(u[Big::new::<19>()], Counted(20));
+    assert_eq!(u[Big::new::<9>()], Counted(10));
+    let snapshot = u.snapshot();
+    u.edit(Big::LAST).0 = 1;
+    assert_eq!(CLONES.with(Cell::get), 21 + Big::SIZE);
+    assert_eq!(snapshot[Big::LAST], Counted(0));
+}
@@ -0,0 +1,34 @@
+// Copyright (C) 2021 Leandro Lisboa Penz <lpenz@lpenz.org>
+// This file is subject to the terms and conditions defined in
+// file 'LICENSE', which is part of this source code package.
+
+use andex::*;
+
+/* Tests for VersionedAndexableArray: */
+
+pub struct MyIdxInner;
+type MyIdx = Andex<MyIdxInner, 6>;
+type MyVersioned = VersionedAndexableArray<MyIdx, u32, { MyIdx::SIZE }>;
+
+#[test]
+fn test_versioned() {
+    let mut v = MyVersioned::from([1, 2, 3, 4, 5, 6]);
+    assert_eq!(v.tick(), 0);
+    assert_eq!(v.iter_modified_since(0).count(), 0);
+    v[MyIdx::new::<1>()] += 10;
+    let t1 = v.tick();
+    assert_eq!(t1, 1);
+    v[&MyIdx::new::<4>()] = 0;
+    assert_eq!(v.replace(MyIdx::new::<1>(), 9), 12);
+    assert_eq!(v.last_modified(MyIdx::new::<1>()), 3);
+    assert_eq!(v.last_modified(MyIdx::new::<4>()), 2);
+    assert_eq!(v.last_modified(MyIdx::FIRST), 0);
+    let since = v
+        .iter_modified_since(t1)
+        .map(|(i, x)| (usize::from(i), *x))
+        .collect::<Vec<_>>();
+    assert_eq!(since, vec![(1, 9), (4, 0)]);
+    assert_eq!(v.iter_modified_since(v.tick()).count(), 0);
+    assert_eq!(v.touch(MyIdx::LAST), 4);
+    assert_eq!(v.clone().into_inner().as_ref(), &[1, 9, 3, 4, 0, 6]);
+}